@@ -0,0 +1,145 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// File where a long simulation saves its state every `every` steps,
+/// so an interrupted run can resume from it
+#[derive(Debug, PartialEq, Clone)]
+pub struct Checkpoint {
+    pub path: PathBuf,
+    pub every: usize,
+}
+
+impl Checkpoint {
+    pub fn new(path: PathBuf, every: usize) -> Self {
+        Checkpoint { path, every }
+    }
+
+    // `--checkpoint PATH` wins over ADVENT_CHECKPOINT, no checkpoint by default
+    pub fn from_args(args: &[String], env_value: Option<String>, every: usize) -> Option<Self> {
+        let mut iter = args.iter().skip(1);
+        while let Some(arg) = iter.next() {
+            if arg == "--checkpoint" {
+                let path = iter.next().expect("--checkpoint needs a path");
+                return Some(Checkpoint::new(PathBuf::from(path), every));
+            }
+        }
+        match env_value {
+            Some(value) if !value.is_empty() => Some(Checkpoint::new(PathBuf::from(value), every)),
+            _ => None,
+        }
+    }
+
+    pub fn from_env(every: usize) -> Option<Self> {
+        let args: Vec<String> = std::env::args().collect();
+        Checkpoint::from_args(&args, std::env::var("ADVENT_CHECKPOINT").ok(), every)
+    }
+
+    pub fn is_due(&self, step: usize) -> bool {
+        self.every > 0 && step.is_multiple_of(self.every)
+    }
+
+    /// Latest state saved for the same `input`, None when there is none:
+    /// nothing saved yet, a file that cannot be read, or a state of another input
+    pub fn load<T: DeserializeOwned>(&self, input: u64) -> Option<T> {
+        let content = fs::read_to_string(&self.path).ok()?;
+        let saved: Saved<T> = serde_json::from_str(&content).ok()?;
+        (saved.input == input).then_some(saved.state)
+    }
+
+    /// Saves `state` as reached from `input`, see [`fingerprint`]
+    pub fn save<T: Serialize>(&self, input: u64, state: &T) -> io::Result<()> {
+        let content = serde_json::to_string(&Saved { input, state })?;
+        // write aside then rename, an interruption never leaves half a file
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, content)?;
+        fs::rename(&tmp_path, &self.path)
+    }
+
+    // once the simulation is over, the next run starts from scratch
+    pub fn clear(&self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// what is written in the file
+#[derive(Serialize, Deserialize)]
+struct Saved<T> {
+    input: u64,
+    state: T,
+}
+
+/// FNV-1a hash of the JSON of `value`, the same from one run to the next,
+/// to recognize the input a checkpoint was saved for
+pub fn fingerprint<T: Serialize>(value: &T) -> u64 {
+    let json = serde_json::to_string(value).expect("the input should serialize to JSON");
+    json.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod checkpoint_test {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn it_can_read_flags() {
+        assert_eq!(Checkpoint::from_args(&args(&["day"]), None, 10), None);
+        assert_eq!(
+            Checkpoint::from_args(&args(&["day", "--checkpoint", "state.json"]), None, 10),
+            Some(Checkpoint::new(PathBuf::from("state.json"), 10))
+        );
+        assert_eq!(
+            Checkpoint::from_args(&args(&["day"]), Some("env.json".to_string()), 10),
+            Some(Checkpoint::new(PathBuf::from("env.json"), 10))
+        );
+    }
+
+    fn temp_checkpoint(name: &str) -> Checkpoint {
+        let path = std::env::temp_dir().join(format!("advent-checkpoint-{}-{}.json", name, std::process::id()));
+        Checkpoint::new(path, 3)
+    }
+
+    #[test]
+    fn it_can_save_and_load() {
+        let checkpoint = temp_checkpoint("save");
+        assert_eq!(checkpoint.load::<Vec<usize>>(1), None);
+        checkpoint.save(1, &vec![1, 2, 3]).unwrap();
+        assert_eq!(checkpoint.load::<Vec<usize>>(1), Some(vec![1, 2, 3]));
+        checkpoint.clear();
+        assert_eq!(checkpoint.load::<Vec<usize>>(1), None);
+        assert!(checkpoint.is_due(6));
+        assert!(!checkpoint.is_due(7));
+    }
+
+    #[test]
+    fn it_ignores_other_inputs_and_bad_files() {
+        let checkpoint = temp_checkpoint("ignore");
+        checkpoint.save(fingerprint(&"first input"), &vec![1, 2, 3]).unwrap();
+        assert_eq!(checkpoint.load::<Vec<usize>>(fingerprint(&"second input")), None);
+        assert_eq!(checkpoint.load::<Vec<usize>>(fingerprint(&"first input")), Some(vec![1, 2, 3]));
+        // another kind of state
+        assert_eq!(checkpoint.load::<String>(fingerprint(&"first input")), None);
+
+        fs::write(&checkpoint.path, "{\"input\": 1, \"sta").unwrap();
+        assert_eq!(checkpoint.load::<Vec<usize>>(1), None);
+        checkpoint.clear();
+
+        let unwritable = Checkpoint::new(PathBuf::from("/nonexistent/dir/state.json"), 3);
+        assert!(unwritable.save(1, &vec![1]).is_err());
+    }
+
+    #[test]
+    fn it_has_stable_fingerprints() {
+        assert_eq!(fingerprint(&""), fingerprint(&String::new()));
+        assert_ne!(fingerprint(&(1, 2)), fingerprint(&(2, 1)));
+        // FNV-1a of `""`, the JSON of an empty string
+        assert_eq!(fingerprint(&""), 0x07cc_7607_b494_9e25);
+    }
+}
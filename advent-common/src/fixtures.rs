@@ -0,0 +1,103 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// a test name from a fixture name, "example-2" gives "example_2"
+fn test_name(input: &Path) -> String {
+    let name: String = input
+        .file_stem()
+        .unwrap()
+        .to_string_lossy()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("case_{}", name)
+    } else {
+        name
+    }
+}
+
+/// The tests of the fixtures in `examples`: each NAME.txt is solved by `krate`
+/// and compared with NAME.part1 and NAME.part2, a part without its file is not checked
+pub fn example_tests(examples: &Path, krate: &str) -> String {
+    let mut inputs: Vec<PathBuf> = match fs::read_dir(examples) {
+        Ok(entries) => entries
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .collect(),
+        Err(_) => vec![],
+    };
+    inputs.sort();
+
+    let mut tests = String::new();
+    for input in inputs {
+        let name = test_name(&input);
+        for (part, solver) in [(1, "solve_one"), (2, "solve_two")] {
+            let expected = input.with_extension(format!("part{}", part));
+            if !expected.exists() {
+                continue;
+            }
+            tests.push_str(&format!(
+                "#[test]
+fn {name}_part_{part}() {{
+    let input = include_str!({input:?});
+    let expected = include_str!({expected:?});
+    let answer = {krate}::{solver}({krate}::parse_input(input.to_string())).to_string();
+    assert_eq!(answer.trim_end(), expected.trim_end());
+}}
+
+"
+            ));
+        }
+    }
+    tests
+}
+
+/// The whole build.rs of a day crate: writes the tests of its examples/ fixtures
+/// to `OUT_DIR/examples.rs`, included by tests/examples.rs
+pub fn write_example_tests() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    // a directory is scanned as a whole, so a new fixture reruns the script
+    println!("cargo:rerun-if-changed=examples");
+    let krate = env::var("CARGO_PKG_NAME").unwrap().replace('-', "_");
+    let tests = example_tests(&manifest_dir.join("examples"), &krate);
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests).unwrap();
+}
+
+#[cfg(test)]
+mod fixtures_test {
+    use super::*;
+
+    #[test]
+    fn it_generates_a_test_per_part() {
+        let dir = env::temp_dir().join(format!("advent-common-fixtures-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for (file, content) in [
+            ("example.txt", "1\n"),
+            ("example.part1", "2\n"),
+            ("example.part2", "3\n"),
+            ("2-large.txt", "4\n"),
+            ("2-large.part2", "5\n"),
+            ("notes.md", ""),
+        ] {
+            fs::write(dir.join(file), content).unwrap();
+        }
+        let tests = example_tests(&dir, "day_42");
+        fs::remove_dir_all(&dir).unwrap();
+        let names: Vec<&str> = tests.lines().filter(|l| l.starts_with("fn ")).collect();
+        assert_eq!(
+            names,
+            vec!["fn case_2_large_part_2() {", "fn example_part_1() {", "fn example_part_2() {"]
+        );
+        assert!(tests.contains("day_42::solve_two(day_42::parse_input(input.to_string()))"));
+        assert_eq!(example_tests(&dir, "day_42"), "");
+    }
+}
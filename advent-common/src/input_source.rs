@@ -0,0 +1,318 @@
+#[cfg(feature = "network")]
+use reqwest::blocking::Client;
+#[cfg(feature = "network")]
+use reqwest::header;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "network")]
+use std::time::Duration;
+
+/// Why an input could not be read, nothing is cached then
+#[derive(Debug, PartialEq)]
+pub enum InputError {
+    // ADVENT_COOKIE is neither in ../.env nor in the environment
+    MissingCookie,
+    // the website could not be reached or its answer read
+    Request { url: String, reason: String },
+    // the website answered an error page (404 before the puzzle unlocks, 429...)
+    Status { url: String, status: u16 },
+    Io { path: PathBuf, reason: String },
+    // built without the network feature
+    NoNetwork,
+    // built without the embedded feature
+    NotEmbedded,
+}
+
+#[cfg(feature = "network")]
+fn base_url() -> String {
+    // can point to the mock server of the aoc crate
+    dotenvy::var("ADVENT_BASE_URL").unwrap_or_else(|_| "https://adventofcode.com".to_string())
+}
+
+#[cfg(feature = "network")]
+fn build_http_client(cookie_value: &str) -> Result<Client, InputError> {
+    let mut headers = header::HeaderMap::new();
+    let cookie =
+        header::HeaderValue::from_str(cookie_value).map_err(|_| InputError::MissingCookie)?;
+    headers.insert("Cookie", cookie);
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .default_headers(headers)
+        .build()
+        .map_err(|e| InputError::Request {
+            url: String::new(),
+            reason: e.to_string(),
+        })
+}
+
+/// Input of `day` from the website at `base_url`, only the body of a successful answer
+#[cfg(feature = "network")]
+pub fn fetch_input(base_url: &str, cookie_value: &str, day: u8) -> Result<String, InputError> {
+    let url = format!("{}/2022/day/{}/input", base_url, day);
+    let request_error = |e: reqwest::Error| InputError::Request {
+        url: url.clone(),
+        reason: e.to_string(),
+    };
+    let response = build_http_client(cookie_value)?
+        .get(&url)
+        .send()
+        .map_err(request_error)?;
+    if !response.status().is_success() {
+        return Err(InputError::Status {
+            url,
+            status: response.status().as_u16(),
+        });
+    }
+    response.text().map_err(request_error)
+}
+
+#[cfg(feature = "network")]
+fn get_puzzle_input(day: u8) -> Result<String, InputError> {
+    // the cookie may also come from the environment
+    let _ = dotenvy::from_filename("../.env");
+    let cookie_value = dotenvy::var("ADVENT_COOKIE").map_err(|_| InputError::MissingCookie)?;
+    fetch_input(&base_url(), &cookie_value, day)
+}
+
+#[cfg(not(feature = "network"))]
+fn get_puzzle_input(_day: u8) -> Result<String, InputError> {
+    Err(InputError::NoNetwork)
+}
+
+/// Content of `path`, else what `fetch` gives, saved to `path` for the next time.
+/// A failed fetch leaves nothing behind
+pub fn cached_or_fetched<F>(path: &Path, fetch: F) -> Result<String, InputError>
+where
+    F: FnOnce() -> Result<String, InputError>,
+{
+    if let Ok(cached) = fs::read_to_string(path) {
+        return Ok(cached);
+    }
+    let fetched = fetch()?;
+    let io_error = |e: std::io::Error| InputError::Io {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, &fetched).map_err(io_error)?;
+    Ok(fetched)
+}
+
+// same place as `aoc wait` stores the inputs
+fn cache_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("../.cache/day-{:02}.txt", day))
+}
+
+/// The cached input of the calling day crate when it is built with its `embedded` feature,
+/// None otherwise, to give to [`InputSource::read_input`]
+#[macro_export]
+macro_rules! embedded_input {
+    () => {{
+        #[cfg(feature = "embedded")]
+        let input: Option<&'static str> = Some(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../.cache/",
+            env!("CARGO_PKG_NAME"),
+            ".txt"
+        )));
+        #[cfg(not(feature = "embedded"))]
+        let input: Option<&'static str> = None;
+        input
+    }};
+}
+
+/// Where the puzzle input is read from, see `from_env`
+#[derive(Debug, PartialEq, Clone)]
+pub enum InputSource {
+    // always ask the website
+    Network,
+    // read the cached input, fetch and save it the first time
+    Cache,
+    Path(PathBuf),
+    Stdin,
+    // compiled in the binary with the `embedded` feature of the day
+    Embedded,
+}
+
+// value of ADVENT_INPUT, anything else than a keyword is a path
+impl From<String> for InputSource {
+    fn from(item: String) -> Self {
+        match item.as_str() {
+            "network" => InputSource::Network,
+            "cache" => InputSource::Cache,
+            "stdin" | "-" => InputSource::Stdin,
+            "embedded" => InputSource::Embedded,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+impl InputSource {
+    // flags win over the environment, cache is the default
+    pub fn from_args(args: &[String], env_value: Option<String>) -> Self {
+        let mut iter = args.iter().skip(1);
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--network" => return InputSource::Network,
+                "--cache" => return InputSource::Cache,
+                "--stdin" => return InputSource::Stdin,
+                "--embedded" => return InputSource::Embedded,
+                "--input" => {
+                    let path = iter.next().expect("--input needs a path");
+                    return InputSource::Path(PathBuf::from(path));
+                }
+                _ => {}
+            }
+        }
+        match env_value {
+            Some(value) if !value.is_empty() => InputSource::from(value),
+            _ => InputSource::Cache,
+        }
+    }
+
+    /// Source picked by the command line flags, then by `ADVENT_INPUT`
+    pub fn from_env() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        InputSource::from_args(&args, std::env::var("ADVENT_INPUT").ok())
+    }
+
+    /// Raw input of `day`, `embedded` being what `embedded_input!` gives in the day crate
+    pub fn read_input(
+        &self,
+        day: u8,
+        embedded: Option<&'static str>,
+    ) -> Result<String, InputError> {
+        match self {
+            InputSource::Network => get_puzzle_input(day),
+            InputSource::Cache => cached_or_fetched(&cache_path(day), || get_puzzle_input(day)),
+            InputSource::Path(path) => fs::read_to_string(path).map_err(|e| InputError::Io {
+                path: path.clone(),
+                reason: e.to_string(),
+            }),
+            InputSource::Stdin => {
+                let mut buffer = String::new();
+                match std::io::stdin().read_to_string(&mut buffer) {
+                    Ok(_) => Ok(buffer),
+                    Err(e) => Err(InputError::Io {
+                        path: PathBuf::from("-"),
+                        reason: e.to_string(),
+                    }),
+                }
+            }
+            InputSource::Embedded => embedded.map(str::to_string).ok_or(InputError::NotEmbedded),
+        }
+    }
+
+    /// Same as `read_input` for the `run` of a day: prints the error and exits with 1
+    pub fn read_input_or_exit(&self, day: u8, embedded: Option<&'static str>) -> String {
+        match self.read_input(day, embedded) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Could not read the input of day {}: {:?}", day, error);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod input_source_test {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn it_defaults_to_cache() {
+        assert_eq!(
+            InputSource::from_args(&args(&["day"]), None),
+            InputSource::Cache
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["day"]), Some("".to_string())),
+            InputSource::Cache
+        );
+    }
+
+    #[test]
+    fn it_can_read_flags() {
+        assert_eq!(
+            InputSource::from_args(&args(&["day", "--stdin"]), None),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["day", "--input", "example.txt"]), None),
+            InputSource::Path(PathBuf::from("example.txt"))
+        );
+        // flags win over the environment
+        assert_eq!(
+            InputSource::from_args(&args(&["day", "--network"]), Some("stdin".to_string())),
+            InputSource::Network
+        );
+    }
+
+    #[test]
+    fn it_can_read_environment() {
+        assert_eq!(
+            InputSource::from_args(&args(&["day"]), Some("embedded".to_string())),
+            InputSource::Embedded
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["day"]), Some("/tmp/input.txt".to_string())),
+            InputSource::Path(PathBuf::from("/tmp/input.txt"))
+        );
+    }
+
+    #[test]
+    fn it_can_read_from_path() {
+        let path = std::env::temp_dir().join(format!("advent-input-{}.txt", std::process::id()));
+        fs::write(&path, "some input\n").unwrap();
+        let source = InputSource::Path(path.clone());
+        assert_eq!(source.read_input(1, None), Ok("some input\n".to_string()));
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            source.read_input(1, None),
+            Err(InputError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn it_can_read_embedded() {
+        assert_eq!(
+            InputSource::Embedded.read_input(1, Some("built in\n")),
+            Ok("built in\n".to_string())
+        );
+        assert_eq!(
+            InputSource::Embedded.read_input(1, None),
+            Err(InputError::NotEmbedded)
+        );
+    }
+
+    #[test]
+    fn it_only_caches_fetched_inputs() {
+        let dir = std::env::temp_dir().join(format!("advent-cache-{}", std::process::id()));
+        let path = dir.join("day-01.txt");
+        let refused = cached_or_fetched(&path, || Err(InputError::NoNetwork));
+        assert_eq!(refused, Err(InputError::NoNetwork));
+        assert!(!path.exists());
+        assert_eq!(
+            cached_or_fetched(&path, || Ok("1\n".to_string())),
+            Ok("1\n".to_string())
+        );
+        // read back without fetching
+        assert_eq!(
+            cached_or_fetched(&path, || Err(InputError::NoNetwork)),
+            Ok("1\n".to_string())
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn it_can_name_cache() {
+        assert_eq!(cache_path(3), PathBuf::from("../.cache/day-03.txt"));
+    }
+}
@@ -0,0 +1,16 @@
+//! Helpers shared by the day crates.
+//!
+//! [`input_source`] reads the puzzle inputs,
+//! [`checkpoint`] saves and resumes the long simulations,
+//! [`trace`] writes the solver events asked for with `--trace`,
+//! [`fixtures`] generates the example tests from build.rs,
+//! `mock_server` (feature `mock-server`) fakes the website for tests and `aoc mock`.
+
+pub mod checkpoint;
+pub mod fixtures;
+pub mod input_source;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
+pub mod trace;
+pub use checkpoint::Checkpoint;
+pub use input_source::{InputError, InputSource};
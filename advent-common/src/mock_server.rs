@@ -0,0 +1,325 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+// Stand-in for adventofcode.com, answering from fixture files laid out like the urls:
+//   fixtures/2022/day/14/index.html    GET /2022/day/14
+//   fixtures/2022/day/14/input         GET /2022/day/14/input
+//   fixtures/2022/day/14/input.status  the status of that answer instead of 200, e.g. 429
+//   fixtures/2022/day/14/answers.tsv   POST /2022/day/14/answer, "level<TAB>answer<TAB>verdict"
+//   fixtures/2022/leaderboard/private/view/123.json
+// Missing fixtures answer 404 (like a locked puzzle), requests without cookie get a 400.
+
+const LOCKED_PAGE: &str = "Please don't repeatedly request this endpoint before it unlocks! \
+The calendar countdown is synchronized with the server time; \
+the link will be enabled on the calendar the instant this puzzle becomes available.";
+const LOGIN_PAGE: &str = "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+
+fn verdict_page(verdict: &str) -> String {
+    let message = match verdict {
+        "correct" => "That's the right answer! You are one gold star closer to saving your vacation.",
+        "too_high" => "That's not the right answer; your answer is too high.",
+        "too_low" => "That's not the right answer; your answer is too low.",
+        "rate_limited" => "You gave an answer too recently; you have to wait after submitting an answer before trying again.",
+        "wrong_level" => "You don't seem to be solving the right level.  Did you already complete it?",
+        _ => "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+    };
+    format!(
+        "<html><body><main><article><p>{}</p></article></main></body></html>",
+        message
+    )
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(b) => {
+                        decoded.push(b);
+                        i += 2;
+                    }
+                    Err(_) => decoded.push(b'%'),
+                }
+            }
+            b => decoded.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+fn parse_form(body: &str) -> Vec<(String, String)> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (percent_decode(k), percent_decode(v)))
+        .collect()
+}
+
+/// Fake adventofcode.com serving a directory of fixtures, see `serve`
+pub struct MockAdventServer {
+    fixtures: PathBuf,
+}
+
+#[derive(Debug, PartialEq)]
+struct MockResponse {
+    status: u16,
+    body: String,
+    content_type: &'static str,
+}
+
+impl MockResponse {
+    fn html(status: u16, body: String) -> Self {
+        MockResponse {
+            status,
+            body,
+            content_type: "text/html",
+        }
+    }
+}
+
+impl MockAdventServer {
+    pub fn new(fixtures: &Path) -> Self {
+        MockAdventServer {
+            fixtures: fixtures.to_path_buf(),
+        }
+    }
+
+    fn fixture_path(&self, url_path: &str) -> PathBuf {
+        let relative: PathBuf = url_path
+            .split('/')
+            .filter(|s| !s.is_empty() && *s != "..")
+            .collect();
+        self.fixtures.join(relative)
+    }
+
+    fn get(&self, url_path: &str, has_cookie: bool) -> MockResponse {
+        if url_path.ends_with("/input") && !has_cookie {
+            return MockResponse::html(400, LOGIN_PAGE.to_string());
+        }
+        let mut path = self.fixture_path(url_path);
+        if path.is_dir() {
+            path = path.join("index.html");
+        }
+        match fs::read_to_string(&path) {
+            Ok(body) => {
+                let content_type = match path.extension().and_then(|e| e.to_str()) {
+                    Some("json") => "application/json",
+                    Some("html") => "text/html",
+                    _ => "text/plain",
+                };
+                let mut status_path = path.into_os_string();
+                status_path.push(".status");
+                let status = fs::read_to_string(status_path)
+                    .ok()
+                    .and_then(|status| status.trim().parse().ok())
+                    .unwrap_or(200);
+                MockResponse {
+                    status,
+                    body,
+                    content_type,
+                }
+            }
+            Err(_) => MockResponse::html(404, LOCKED_PAGE.to_string()),
+        }
+    }
+
+    fn post_answer(&self, url_path: &str, has_cookie: bool, body: &str) -> MockResponse {
+        if !has_cookie {
+            return MockResponse::html(400, LOGIN_PAGE.to_string());
+        }
+        let day_path = url_path.trim_end_matches("/answer");
+        let script_path = self.fixture_path(day_path).join("answers.tsv");
+        let script = match fs::read_to_string(script_path) {
+            Ok(s) => s,
+            Err(_) => return MockResponse::html(404, LOCKED_PAGE.to_string()),
+        };
+        let form = parse_form(body);
+        let field = |name: &str| {
+            form.iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.clone())
+                .unwrap_or_default()
+        };
+        let (level, answer) = (field("level"), field("answer"));
+        let verdict = script
+            .lines()
+            .filter_map(|line| {
+                let cols: Vec<&str> = line.split('\t').collect();
+                match cols.as_slice() {
+                    [l, a, v] if *l == level && *a == answer => Some(v.trim().to_string()),
+                    _ => None,
+                }
+            })
+            .next()
+            .unwrap_or_else(|| "wrong".to_string());
+        MockResponse::html(200, verdict_page(&verdict))
+    }
+
+    fn respond(&self, method: &Method, url: &str, has_cookie: bool, body: &str) -> MockResponse {
+        let url_path = url.split('?').next().unwrap_or("/");
+        match method {
+            Method::Get => self.get(url_path, has_cookie),
+            Method::Post if url_path.ends_with("/answer") => {
+                self.post_answer(url_path, has_cookie, body)
+            }
+            _ => MockResponse::html(404, "Not found".to_string()),
+        }
+    }
+
+    fn handle(&self, mut request: Request) {
+        let has_cookie = request
+            .headers()
+            .iter()
+            .any(|h| h.field.equiv("Cookie") && !h.value.as_str().trim().is_empty());
+        let mut body = String::new();
+        request.as_reader().read_to_string(&mut body).unwrap_or(0);
+        let mock = self.respond(request.method(), request.url(), has_cookie, &body);
+        let header = Header::from_bytes("Content-Type", mock.content_type).unwrap();
+        let response = Response::from_string(mock.body)
+            .with_status_code(mock.status)
+            .with_header(header);
+        let _ = request.respond(response);
+    }
+
+    /// Answers on `address` ("127.0.0.1:0" picks a free port) until dropped
+    pub fn serve(self, address: &str) -> RunningServer {
+        serve_requests(address, move |request| self.handle(request))
+    }
+}
+
+/// Hands each request to `handle` on a background thread until the server is dropped
+pub fn serve_requests<F>(address: &str, handle: F) -> RunningServer
+where
+    F: Fn(Request) + Send + 'static,
+{
+    let server = Arc::new(Server::http(address).unwrap());
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let thread_server = server.clone();
+    let thread = std::thread::spawn(move || {
+        for request in thread_server.incoming_requests() {
+            handle(request);
+        }
+    });
+    RunningServer {
+        base_url,
+        server,
+        thread: Some(thread),
+    }
+}
+
+/// A server answering on a background thread, stopped when dropped
+pub struct RunningServer {
+    pub base_url: String,
+    server: Arc<Server>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl RunningServer {
+    pub fn join(mut self) {
+        if let Some(thread) = self.thread.take() {
+            thread.join().unwrap();
+        }
+    }
+}
+
+impl Drop for RunningServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod mock_server_test {
+    use super::*;
+
+    // the fixtures of the aoc crate, served by `aoc mock`
+    fn fixtures() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../aoc/fixtures")
+    }
+
+    #[test]
+    fn it_can_decode_forms() {
+        assert_eq!(
+            parse_form("level=1&answer=CMZ"),
+            vec![
+                ("level".to_string(), "1".to_string()),
+                ("answer".to_string(), "CMZ".to_string())
+            ]
+        );
+        assert_eq!(percent_decode("a+b%2Cc"), "a b,c");
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+
+    #[test]
+    fn it_serves_fixtures() {
+        let mock = MockAdventServer::new(&fixtures());
+        let input = mock.respond(&Method::Get, "/2022/day/1/input", true, "");
+        assert_eq!(input.status, 200);
+        assert!(input.body.starts_with("1000\n2000\n3000\n"));
+
+        let page = mock.respond(&Method::Get, "/2022/day/1", true, "");
+        assert_eq!(page.status, 200);
+        assert_eq!(page.content_type, "text/html");
+        assert!(page.body.contains("Calorie Counting"));
+
+        let board = mock.respond(
+            &Method::Get,
+            "/2022/leaderboard/private/view/123.json",
+            true,
+            "",
+        );
+        assert_eq!(board.content_type, "application/json");
+        assert!(board.body.contains("\"owner_id\""));
+    }
+
+    #[test]
+    fn it_answers_errors() {
+        let mock = MockAdventServer::new(&fixtures());
+        let locked = mock.respond(&Method::Get, "/2022/day/25/input", true, "");
+        assert_eq!(locked.status, 404);
+        assert!(locked.body.contains("before it unlocks"));
+
+        let no_cookie = mock.respond(&Method::Get, "/2022/day/1/input", false, "");
+        assert_eq!(no_cookie.status, 400);
+
+        let escaping = mock.respond(&Method::Get, "/../Cargo.toml", true, "");
+        assert_eq!(escaping.status, 404);
+    }
+
+    #[test]
+    fn it_answers_scripted_statuses() {
+        let dir = std::env::temp_dir().join(format!("mock-status-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2022/day/2")).unwrap();
+        fs::write(dir.join("2022/day/2/input"), "Too many requests").unwrap();
+        fs::write(dir.join("2022/day/2/input.status"), "429\n").unwrap();
+        let mock = MockAdventServer::new(&dir);
+        let limited = mock.respond(&Method::Get, "/2022/day/2/input", true, "");
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(limited.status, 429);
+        assert_eq!(limited.body, "Too many requests");
+    }
+
+    #[test]
+    fn it_answers_scripted_verdicts() {
+        let mock = MockAdventServer::new(&fixtures());
+        let answer = |body: &str| {
+            mock.respond(&Method::Post, "/2022/day/1/answer", true, body)
+                .body
+        };
+        assert!(answer("level=1&answer=24000").contains("That's the right answer"));
+        assert!(answer("level=1&answer=99999").contains("too high"));
+        assert!(answer("level=2&answer=1").contains("too recently"));
+        assert!(answer("level=2&answer=123").contains("That's not the right answer."));
+    }
+}
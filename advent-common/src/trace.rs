@@ -0,0 +1,145 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+// events of the solvers, one JSON per line, only written with `--trace`
+static TRACE_FILE: Mutex<Option<TraceFile>> = Mutex::new(None);
+// checked before taking the lock, so emitting costs nothing when tracing is off
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+struct TraceFile {
+    day: u8,
+    writer: BufWriter<File>,
+}
+
+// the lock may be poisoned by a panic while writing
+fn trace_file() -> MutexGuard<'static, Option<TraceFile>> {
+    match TRACE_FILE.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+// `--trace` writes to trace.jsonl, `--trace PATH` or ADVENT_TRACE elsewhere
+pub fn path_from_args(args: &[String], env_value: Option<String>) -> Option<PathBuf> {
+    let mut iter = args.iter().skip(1).peekable();
+    while let Some(arg) = iter.next() {
+        if arg == "--trace" {
+            return match iter.peek() {
+                Some(path) if !path.starts_with("--") => Some(PathBuf::from(path)),
+                _ => Some(PathBuf::from("trace.jsonl")),
+            };
+        }
+    }
+    match env_value {
+        Some(value) if !value.is_empty() => Some(PathBuf::from(value)),
+        _ => None,
+    }
+}
+
+/// Starts writing the events of `day` when asked by the command line or ADVENT_TRACE,
+/// they are flushed when the guard is dropped, even if the solver panics
+pub fn start_from_env(day: u8) -> TraceGuard {
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(path) = path_from_args(&args, std::env::var("ADVENT_TRACE").ok()) {
+        match File::create(&path) {
+            Ok(file) => {
+                *trace_file() = Some(TraceFile { day, writer: BufWriter::new(file) });
+                ENABLED.store(true, Ordering::Relaxed);
+            }
+            Err(e) => eprintln!("Not tracing, could not create {}: {}", path.display(), e),
+        }
+    }
+    TraceGuard
+}
+
+pub struct TraceGuard;
+
+impl Drop for TraceGuard {
+    fn drop(&mut self) {
+        ENABLED.store(false, Ordering::Relaxed);
+        if let Some(mut file) = trace_file().take() {
+            let _ = file.writer.flush();
+        }
+    }
+}
+
+// the event fields, tagged with the day
+fn event_line<E: Serialize>(event: &E, day: u8) -> serde_json::Result<String> {
+    let mut value = serde_json::to_value(event)?;
+    value["day"] = day.into();
+    Ok(value.to_string())
+}
+
+/// Records an event if tracing was started, does nothing otherwise.
+/// A failed write stops the tracing instead of the solver.
+pub fn emit<E: Serialize>(event: E) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let mut guard = trace_file();
+    let Some(file) = guard.as_mut() else {
+        return;
+    };
+    let written = event_line(&event, file.day)
+        .map_err(|e| e.to_string())
+        .and_then(|line| writeln!(file.writer, "{}", line).map_err(|e| e.to_string()));
+    if let Err(e) = written {
+        eprintln!("Stopped tracing: {}", e);
+        ENABLED.store(false, Ordering::Relaxed);
+        *guard = None;
+    }
+}
+
+#[cfg(test)]
+mod trace_test {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn it_can_read_flags() {
+        assert_eq!(path_from_args(&args(&["day"]), None), None);
+        assert_eq!(
+            path_from_args(&args(&["day", "--trace"]), None),
+            Some(PathBuf::from("trace.jsonl"))
+        );
+        assert_eq!(
+            path_from_args(&args(&["day", "--trace", "--stdin"]), None),
+            Some(PathBuf::from("trace.jsonl"))
+        );
+        assert_eq!(
+            path_from_args(&args(&["day", "--trace", "out.jsonl"]), None),
+            Some(PathBuf::from("out.jsonl"))
+        );
+        assert_eq!(
+            path_from_args(&args(&["day"]), Some("env.jsonl".to_string())),
+            Some(PathBuf::from("env.jsonl"))
+        );
+    }
+
+    #[test]
+    fn it_can_tag_events() {
+        #[derive(Serialize)]
+        #[serde(tag = "event", rename_all = "snake_case")]
+        enum Event {
+            Found { position: usize },
+        }
+        let line = event_line(&Event::Found { position: 7 }, 12).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["event"], "found");
+        assert_eq!(value["position"], 7);
+        assert_eq!(value["day"], 12);
+    }
+
+    #[test]
+    fn it_ignores_events_when_not_started() {
+        emit(serde_json::json!({ "event": "ignored" }));
+        assert!(trace_file().is_none());
+    }
+}
@@ -0,0 +1,277 @@
+//! Python bindings of the Rust solvers, built with maturin as the `advent2022` module.
+//!
+//! Each day is a submodule (`advent2022.day07`...) with `parse_input`, `solve_one`
+//! and `solve_two` taking the raw puzzle input, everything comes back as plain
+//! Python values (`int`, `str`, `list`, `dict`). `FileTree`, `Forest`, `Rope` and
+//! `FallingGrid` are classes to look into the simulations.
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+use serde::Serialize;
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+// serde gives the shape of every parsed input, json.loads makes it Python
+fn to_python<T: Serialize>(py: Python<'_>, value: &T) -> PyResult<PyObject> {
+    let text = serde_json::to_string(value).unwrap();
+    let loads = py.import("json")?.getattr("loads")?;
+    Ok(loads.call1((text,))?.unbind())
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+thread_local! {
+    // set while this thread runs a solver, its panic becomes a ValueError
+    static IN_SOLVER: Cell<bool> = const { Cell::new(false) };
+}
+
+// installed once and kept, other panics still reach the previous hook
+fn quiet_solver_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !IN_SOLVER.with(|flag| flag.get()) {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+// a solver panics on input it cannot read, Python gets a ValueError with the message
+fn solved<T: Serialize>(py: Python<'_>, solve: impl FnOnce() -> T) -> PyResult<PyObject> {
+    quiet_solver_panics();
+    IN_SOLVER.with(|flag| flag.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(solve));
+    IN_SOLVER.with(|flag| flag.set(false));
+    match result {
+        Ok(value) => to_python(py, &value),
+        Err(payload) => Err(PyValueError::new_err(panic_message(payload))),
+    }
+}
+
+macro_rules! day_module {
+    ($name:ident, $krate:ident) => {
+        mod $name {
+            use super::*;
+
+            /// The parsed puzzle input, ValueError if it cannot be read
+            #[pyfunction]
+            fn parse_input(py: Python<'_>, puzzle_input: String) -> PyResult<PyObject> {
+                solved(py, || $krate::parse_input(puzzle_input))
+            }
+
+            /// The answer of the first part, ValueError if there is none
+            #[pyfunction]
+            fn solve_one(py: Python<'_>, puzzle_input: String) -> PyResult<PyObject> {
+                solved(py, || $krate::solve_one($krate::parse_input(puzzle_input)))
+            }
+
+            /// The answer of the second part, ValueError if there is none
+            #[pyfunction]
+            fn solve_two(py: Python<'_>, puzzle_input: String) -> PyResult<PyObject> {
+                solved(py, || $krate::solve_two($krate::parse_input(puzzle_input)))
+            }
+
+            pub(crate) fn register(parent: &Bound<'_, PyModule>) -> PyResult<()> {
+                let module = PyModule::new(parent.py(), stringify!($name))?;
+                module.add("DAY", $krate::DAY)?;
+                module.add_function(wrap_pyfunction!(parse_input, &module)?)?;
+                module.add_function(wrap_pyfunction!(solve_one, &module)?)?;
+                module.add_function(wrap_pyfunction!(solve_two, &module)?)?;
+                parent.add_submodule(&module)
+            }
+        }
+    };
+}
+
+day_module!(day01, day_01);
+day_module!(day02, day_02);
+day_module!(day03, day_03);
+day_module!(day04, day_04);
+day_module!(day05, day_05);
+day_module!(day06, day_06);
+day_module!(day07, day_07);
+day_module!(day08, day_08);
+day_module!(day09, day_09);
+day_module!(day10, day_10);
+day_module!(day11, day_11);
+day_module!(day14, day_14);
+
+/// Directories of a day 7 terminal log
+#[pyclass(name = "FileTree")]
+struct PyFileTree(day_07::FileTree);
+
+#[pymethods]
+impl PyFileTree {
+    #[new]
+    fn new(terminal_log: String) -> Self {
+        PyFileTree(day_07::FileTree::from(day_07::parse_input(terminal_log)))
+    }
+
+    /// Total size of each directory, by full path
+    fn directories(&self) -> BTreeMap<String, usize> {
+        self.0
+            .get_directories()
+            .iter()
+            .map(|dir| (dir.full_path(), dir.total_size))
+            .collect()
+    }
+
+    /// Total size of a directory, None if unknown
+    fn size(&self, path: &str) -> Option<usize> {
+        self.directories().get(path).copied()
+    }
+}
+
+/// Tree heights of day 8, with the visibility from the edges
+#[pyclass(name = "Forest")]
+struct PyForest(day_08::Forest);
+
+#[pymethods]
+impl PyForest {
+    #[new]
+    fn new(heights: String) -> Self {
+        let mut forest = day_08::Forest::from(day_08::parse_input(heights));
+        forest.update_visibility();
+        PyForest(forest)
+    }
+
+    /// (rows, columns)
+    fn size(&self) -> (usize, usize) {
+        self.0.get_size()
+    }
+
+    /// One row of booleans per row of trees, True if seen from any edge
+    fn visibility(&self) -> Vec<Vec<bool>> {
+        let (_, columns) = self.0.get_size();
+        self.0
+            .get_trees_flatten()
+            .chunks(columns)
+            .map(|row| row.iter().map(|tree| tree.is_any_visible()).collect())
+            .collect()
+    }
+
+    /// IndexError outside the grid
+    fn is_visible(&self, row: usize, col: usize) -> PyResult<bool> {
+        let (rows, columns) = self.0.get_size();
+        if row >= rows || col >= columns {
+            return Err(PyIndexError::new_err(format!(
+                "({}, {}) is outside the {}x{} forest",
+                row, col, rows, columns
+            )));
+        }
+        Ok(self.0.get_trees_flatten()[row * columns + col].is_any_visible())
+    }
+
+    fn visible_count(&self) -> usize {
+        self.0
+            .get_trees_flatten()
+            .iter()
+            .filter(|tree| tree.is_any_visible())
+            .count()
+    }
+}
+
+/// Day 9 rope, starting with all its knots on (0, 0)
+#[pyclass(name = "Rope")]
+struct PyRope(day_09::Rope);
+
+#[pymethods]
+impl PyRope {
+    #[new]
+    #[pyo3(signature = (knots = 2))]
+    fn new(knots: usize) -> Self {
+        PyRope(day_09::Rope::new(day_09::Point { x: 0, y: 0 }, knots))
+    }
+
+    /// Moves the head by a line of the input, e.g. "R 4"
+    fn apply(&mut self, movement: String) {
+        self.0.apply_movement(day_09::Movement::from(movement));
+    }
+
+    /// Applies every line of a puzzle input
+    fn apply_all(&mut self, puzzle_input: String) {
+        for movement in day_09::parse_input(puzzle_input) {
+            self.0.apply_movement(movement);
+        }
+    }
+
+    /// Positions (x, y) of the knots, head first
+    fn knots(&self) -> Vec<(isize, isize)> {
+        self.0.get_knots().iter().map(|p| (p.x, p.y)).collect()
+    }
+
+    /// Positions (x, y) visited by the tail
+    fn tail_visited(&self) -> BTreeSet<(isize, isize)> {
+        self.0
+            .get_tail_memory()
+            .iter()
+            .map(|p| (p.x, p.y))
+            .collect()
+    }
+}
+
+/// Day 14 cave, the rocks then each grain of sand dropped
+#[pyclass(name = "FallingGrid")]
+struct PyFallingGrid(day_14::FallingGrid);
+
+#[pymethods]
+impl PyFallingGrid {
+    #[new]
+    #[pyo3(signature = (rock_paths, floor = false))]
+    fn new(rock_paths: String, floor: bool) -> Self {
+        let mut grid = day_14::prepare_grid(day_14::parse_input(rock_paths));
+        if floor {
+            grid.set_floor();
+        }
+        PyFallingGrid(grid)
+    }
+
+    /// Drops a grain from the source, True if it came to rest
+    fn drop_grain(&mut self) -> bool {
+        self.0.fall_one_sand(day_14::STARTING_FALL) == day_14::GrainStatus::Stopped
+    }
+
+    fn is_occupied(&self, x: usize, y: usize) -> bool {
+        self.0.is_point_occupied(&day_14::Point { x, y })
+    }
+
+    /// Rocks and grains at rest
+    fn __len__(&self) -> usize {
+        self.0.get_number_of_points()
+    }
+}
+
+#[pymodule]
+fn advent2022(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    day01::register(m)?;
+    day02::register(m)?;
+    day03::register(m)?;
+    day04::register(m)?;
+    day05::register(m)?;
+    day06::register(m)?;
+    day07::register(m)?;
+    day08::register(m)?;
+    day09::register(m)?;
+    day10::register(m)?;
+    day11::register(m)?;
+    day14::register(m)?;
+    m.add_class::<PyFileTree>()?;
+    m.add_class::<PyForest>()?;
+    m.add_class::<PyRope>()?;
+    m.add_class::<PyFallingGrid>()?;
+    Ok(())
+}
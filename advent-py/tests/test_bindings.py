@@ -0,0 +1,109 @@
+from pathlib import Path
+
+import advent2022
+import pytest
+
+REPO_ROOT = Path(__file__).parents[2]
+DAYS = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 14]
+
+
+def example(day: int, name: str = "example") -> str:
+    return (REPO_ROOT / f"day-{day:02}" / "examples" / f"{name}.txt").read_text()
+
+
+def fixtures():
+    # the same examples/ fixtures as the Rust tests
+    for day in DAYS:
+        for expected in sorted((REPO_ROOT / f"day-{day:02}" / "examples").glob("*.part*")):
+            part = int(expected.suffix[len(".part"):])
+            yield pytest.param(day, expected.stem, part, expected.read_text().strip(),
+                               id=f"day{day:02}-{expected.stem}-part{part}")
+
+
+@pytest.mark.parametrize("day,name,part,expected", fixtures())
+def test_solves_examples(day, name, part, expected):
+    module = getattr(advent2022, f"day{day:02}")
+    solve = module.solve_one if part == 1 else module.solve_two
+    assert str(solve(example(day, name))) == expected
+
+
+def test_returns_native_types():
+    assert advent2022.day01.solve_one(example(1)) == 24000
+    assert advent2022.day05.solve_two(example(5)) == "MCD"
+    assert advent2022.day01.parse_input(example(1))[0] == [1000, 2000, 3000]
+    assert advent2022.day02.parse_input("A Y\nB X\n") == [
+        {"opponent": "Rock", "column": "Y"},
+        {"opponent": "Paper", "column": "X"},
+    ]
+    warehouse = advent2022.day05.parse_input(example(5))
+    assert warehouse["instructions"][0] == {"quantity": 1, "from": 2, "to": 1}
+    assert advent2022.day09.parse_input("R 4\n") == [{"x": 4, "y": 0}]
+    assert advent2022.day06.DAY == 6
+
+
+def test_reports_bad_inputs():
+    with pytest.raises(ValueError, match="InvalidDigit"):
+        advent2022.day01.solve_one("1000\nlots\n\n")
+    with pytest.raises(ValueError, match="PartialGroup"):
+        advent2022.day03.solve_two("ab\ncd\n")
+
+
+def test_file_tree():
+    tree = advent2022.FileTree(example(7))
+    directories = tree.directories()
+    assert directories["/"] == 48381165
+    assert tree.size("/a/e") == 584
+    assert tree.size("/nowhere") is None
+    assert sum(size for size in directories.values() if size <= 100000) == 95437
+
+
+def test_forest():
+    forest = advent2022.Forest(example(8))
+    assert forest.size() == (5, 5)
+    assert forest.visible_count() == 21
+    assert forest.is_visible(1, 1)
+    assert not forest.is_visible(1, 3)
+    with pytest.raises(IndexError):
+        forest.is_visible(5, 0)
+    with pytest.raises(IndexError):
+        forest.is_visible(0, 5)
+    assert forest.visibility()[0] == [True] * 5
+
+
+def test_rope():
+    rope = advent2022.Rope()
+    rope.apply("R 4")
+    assert rope.knots() == [(4, 0), (3, 0)]
+    assert rope.tail_visited() == {(0, 0), (1, 0), (2, 0), (3, 0)}
+
+    rope = advent2022.Rope()
+    rope.apply_all(example(9))
+    assert len(rope.tail_visited()) == 13
+
+    long_rope = advent2022.Rope(10)
+    long_rope.apply_all(example(9, "larger"))
+    assert len(long_rope.knots()) == 10
+    assert len(long_rope.tail_visited()) == 36
+    assert (0, 0) in long_rope.tail_visited()
+
+
+def test_falling_grid():
+    grid = advent2022.FallingGrid(example(14))
+    rocks = len(grid)
+    assert grid.is_occupied(498, 4)
+    assert not grid.is_occupied(500, 8)
+    grains = 0
+    while grid.drop_grain():
+        grains += 1
+    assert grains == 24
+    assert len(grid) == rocks + 24
+    assert grid.is_occupied(500, 8)
+
+    with_floor = advent2022.FallingGrid(example(14), floor=True)
+    assert len(with_floor) > rocks
+
+
+def test_panics_become_exceptions():
+    # the second part of day 8 is not written yet
+    with pytest.raises(BaseException):
+        advent2022.day08.solve_two(example(8))
@@ -0,0 +1,247 @@
+//! Local API over the solvers, for trying inputs without a Rust toolchain:
+//!   POST /api/2022/day/14/part/2   with the input as body
+//! answers {"day": 14, "part": 2, "answer": "93", "elapsed_ms": 1.5},
+//! or {"error": "timeout", "message": "..."} with a 4xx/5xx status.
+//!
+//! Each request is solved by its own process, killed when it runs out of time.
+
+use advent_common::mock_server::{serve_requests, RunningServer};
+use serde_json::{json, Value};
+use solver::{run_solver, Outcome};
+use std::sync::Arc;
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response};
+
+mod solver;
+
+pub struct SolverService {
+    // run with the day and the part as two more arguments
+    command: Vec<String>,
+    days: Vec<u8>,
+    timeout: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+struct ServiceResponse {
+    status: u16,
+    body: Value,
+}
+
+impl ServiceResponse {
+    fn error(status: u16, kind: &str, message: String) -> Self {
+        ServiceResponse {
+            status,
+            body: json!({ "error": kind, "message": message }),
+        }
+    }
+}
+
+// (day, part) of "/api/2022/day/{n}/part/{p}"
+fn parse_route(url_path: &str) -> Option<(u8, u8)> {
+    let segments: Vec<&str> = url_path.trim_end_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["", "api", "2022", "day", day, "part", part] => {
+            Some((day.parse().ok()?, part.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
+impl SolverService {
+    /// Answers for `days` by running `command DAY PART`, the input on its stdin
+    /// and the answer printed on its stdout, a panic exiting with code 101
+    pub fn new(command: Vec<String>, days: Vec<u8>, timeout: Duration) -> Self {
+        assert!(!command.is_empty(), "the solver command is empty");
+        SolverService {
+            command,
+            days,
+            timeout,
+        }
+    }
+
+    fn respond(&self, method: &Method, url: &str, input: String) -> ServiceResponse {
+        let url_path = url.split('?').next().unwrap_or("/");
+        let Some((day, part)) = parse_route(url_path) else {
+            return ServiceResponse::error(404, "not_found", format!("No route for {}", url_path));
+        };
+        if *method != Method::Post {
+            return ServiceResponse::error(
+                405,
+                "method_not_allowed",
+                "POST the input instead".to_string(),
+            );
+        }
+        if !self.days.contains(&day) {
+            return ServiceResponse::error(
+                404,
+                "unknown_day",
+                format!("Day {} has no solver", day),
+            );
+        }
+        if part != 1 && part != 2 {
+            return ServiceResponse::error(
+                404,
+                "unknown_part",
+                format!("Part {} does not exist", part),
+            );
+        }
+        if input.trim().is_empty() {
+            return ServiceResponse::error(
+                400,
+                "empty_input",
+                "The body should be the puzzle input".to_string(),
+            );
+        }
+        match run_solver(&self.command, day, part, input, self.timeout) {
+            Outcome::Answer { answer, elapsed } => ServiceResponse {
+                status: 200,
+                body: json!({
+                    "day": day,
+                    "part": part,
+                    "answer": answer,
+                    "elapsed_ms": elapsed.as_secs_f64() * 1000.0,
+                }),
+            },
+            Outcome::Panicked(message) => ServiceResponse::error(500, "solver_panicked", message),
+            Outcome::Failed(message) => ServiceResponse::error(500, "solver_failed", message),
+            Outcome::TimedOut => ServiceResponse::error(
+                504,
+                "timeout",
+                format!("No answer after {:.1}s", self.timeout.as_secs_f64()),
+            ),
+        }
+    }
+
+    fn handle(&self, mut request: Request) {
+        let mut input = String::new();
+        let response = match request.as_reader().read_to_string(&mut input) {
+            Ok(_) => self.respond(request.method(), request.url(), input),
+            Err(e) => ServiceResponse::error(400, "bad_body", e.to_string()),
+        };
+        let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let _ = request.respond(
+            Response::from_string(response.body.to_string())
+                .with_status_code(response.status)
+                .with_header(header),
+        );
+    }
+
+    /// Each request is answered on its own thread, so a slow solver doesn't hold up the others
+    pub fn serve(self, address: &str) -> RunningServer {
+        let service = Arc::new(self);
+        serve_requests(address, move |request| {
+            let service = service.clone();
+            std::thread::spawn(move || service.handle(request));
+        })
+    }
+}
+
+#[cfg(test)]
+mod service_test {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    // day 1 counts the lines, day 2 panics, day 3 never answers
+    fn service(timeout: Duration) -> SolverService {
+        let script = "case $1 in
+            1) echo \"$2:$(wc -l)\" ;;
+            2) echo 'boom' >&2; exit 101 ;;
+            3) exec sleep 10 ;;
+        esac";
+        let command = ["sh", "-c", script, "solver"].map(String::from).to_vec();
+        SolverService::new(command, vec![1, 2, 3], timeout)
+    }
+
+    fn post(service: &SolverService, url: &str, input: &str) -> ServiceResponse {
+        service.respond(&Method::Post, url, input.to_string())
+    }
+
+    // the status and body of a raw HTTP/1.0 request
+    fn send(base_url: &str, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(base_url.trim_start_matches("http://")).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.0\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn it_can_parse_routes() {
+        assert_eq!(parse_route("/api/2022/day/14/part/2"), Some((14, 2)));
+        assert_eq!(parse_route("/api/2022/day/1/part/1/"), Some((1, 1)));
+        assert_eq!(parse_route("/api/2021/day/1/part/1"), None);
+        assert_eq!(parse_route("/api/2022/day/x/part/1"), None);
+    }
+
+    #[test]
+    fn it_answers_with_timing() {
+        let response = post(
+            &service(Duration::from_secs(5)),
+            "/api/2022/day/1/part/2",
+            "a\nb\n",
+        );
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["answer"], "2:2");
+        assert_eq!(response.body["day"], 1);
+        assert!(response.body["elapsed_ms"].as_f64().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn it_answers_errors_as_json() {
+        let service = service(Duration::from_millis(200));
+        let error = |url: &str, input: &str| {
+            let response = post(&service, url, input);
+            (
+                response.status,
+                response.body["error"].as_str().unwrap().to_string(),
+            )
+        };
+        assert_eq!(
+            error("/api/2022/day/9/part/1", "x"),
+            (404, "unknown_day".to_string())
+        );
+        assert_eq!(
+            error("/api/2022/day/1/part/3", "x"),
+            (404, "unknown_part".to_string())
+        );
+        assert_eq!(error("/nothing", "x"), (404, "not_found".to_string()));
+        assert_eq!(
+            error("/api/2022/day/1/part/1", " \n"),
+            (400, "empty_input".to_string())
+        );
+        assert_eq!(
+            error("/api/2022/day/2/part/1", "x"),
+            (500, "solver_panicked".to_string())
+        );
+        assert_eq!(
+            error("/api/2022/day/3/part/1", "x"),
+            (504, "timeout".to_string())
+        );
+        let response = post(&service, "/api/2022/day/2/part/1", "x");
+        assert_eq!(response.body["message"], "boom");
+    }
+
+    #[test]
+    fn it_serves_over_http() {
+        let server = service(Duration::from_millis(500)).serve("127.0.0.1:0");
+        let (status, body) = send(&server.base_url, "POST", "/api/2022/day/1/part/1", "a\n");
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"], "1:1");
+        let (status, body) = send(&server.base_url, "GET", "/api/2022/day/1/part/1", "");
+        assert_eq!(status, 405);
+        assert_eq!(body["error"], "method_not_allowed");
+        let (status, _) = send(&server.base_url, "POST", "/api/2022/day/3/part/1", "a\n");
+        assert_eq!(status, 504);
+    }
+}
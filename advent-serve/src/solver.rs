@@ -0,0 +1,158 @@
+use std::io::{Read, Write};
+use std::process::{Child, Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// the exit code of a Rust program that panicked
+const PANIC_EXIT_CODE: i32 = 101;
+const POLL: Duration = Duration::from_millis(5);
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Outcome {
+    Answer { answer: String, elapsed: Duration },
+    // the solver panicked, with what it printed on stderr
+    Panicked(String),
+    Failed(String),
+    // the solver was killed
+    TimedOut,
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut content = String::new();
+        let _ = pipe.read_to_string(&mut content);
+        content
+    })
+}
+
+fn kill(child: &mut Child) {
+    let _ = child.kill();
+    // reaped, no zombie left behind
+    let _ = child.wait();
+}
+
+// runs `command DAY PART` with the input on stdin, its stdout being the answer,
+// killing it once it outlives `timeout`
+pub(crate) fn run_solver(
+    command: &[String],
+    day: u8,
+    part: u8,
+    input: String,
+    timeout: Duration,
+) -> Outcome {
+    let start = Instant::now();
+    let spawned = Command::new(&command[0])
+        .args(&command[1..])
+        .arg(day.to_string())
+        .arg(part.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => return Outcome::Failed(format!("Could not start {:?}: {}", command[0], e)),
+    };
+    // written aside, a solver may answer (or hang) before reading all of it
+    let mut stdin = child.stdin.take().unwrap();
+    thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let stdout = read_in_background(child.stdout.take().unwrap());
+    let stderr = read_in_background(child.stderr.take().unwrap());
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() >= timeout => {
+                kill(&mut child);
+                return Outcome::TimedOut;
+            }
+            Ok(None) => thread::sleep(POLL),
+            Err(e) => {
+                kill(&mut child);
+                return Outcome::Failed(e.to_string());
+            }
+        }
+    };
+    let elapsed = start.elapsed();
+    let answer = stdout.join().unwrap_or_default().trim_end().to_string();
+    let message = stderr.join().unwrap_or_default().trim_end().to_string();
+    match status.code() {
+        Some(0) if answer.is_empty() => Outcome::Failed("The solver printed no answer".to_string()),
+        Some(0) => Outcome::Answer { answer, elapsed },
+        Some(PANIC_EXIT_CODE) => Outcome::Panicked(message),
+        _ if message.is_empty() => Outcome::Failed(format!("The solver stopped: {}", status)),
+        _ => Outcome::Failed(message),
+    }
+}
+
+#[cfg(test)]
+mod solver_test {
+    use super::*;
+    use std::path::Path;
+
+    // a shell script as solver, given the day and part as $1 and $2
+    fn script(script: &str) -> Vec<String> {
+        vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            script.to_string(),
+            "solver".to_string(),
+        ]
+    }
+
+    fn run(command: &[String], input: &str) -> Outcome {
+        run_solver(command, 14, 2, input.to_string(), Duration::from_secs(5))
+    }
+
+    #[test]
+    fn it_answers_with_stdout() {
+        let Outcome::Answer { answer, .. } = run(&script("echo \"$1 $2 $(wc -l)\""), "a\nb\n")
+        else {
+            panic!("no answer");
+        };
+        assert_eq!(answer, "14 2 2");
+    }
+
+    #[test]
+    fn it_reports_failures() {
+        assert_eq!(
+            run(
+                &script("echo 'attempt to subtract with overflow' >&2; exit 101"),
+                ""
+            ),
+            Outcome::Panicked("attempt to subtract with overflow".to_string())
+        );
+        assert_eq!(
+            run(&script("echo 'no such day' >&2; exit 2"), ""),
+            Outcome::Failed("no such day".to_string())
+        );
+        assert_eq!(
+            run(&script("exit 3"), ""),
+            Outcome::Failed("The solver stopped: exit status: 3".to_string())
+        );
+        assert_eq!(
+            run(&script("true"), ""),
+            Outcome::Failed("The solver printed no answer".to_string())
+        );
+        let missing = run(&["./no-such-solver".to_string()], "");
+        assert!(
+            matches!(missing, Outcome::Failed(message) if message.starts_with("Could not start"))
+        );
+    }
+
+    #[test]
+    fn it_kills_slow_solvers() {
+        let dir = std::env::temp_dir().join(format!("advent-serve-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let pid_file = dir.join("pid");
+        let command = script(&format!("echo $$ > {:?}; exec sleep 10", pid_file));
+
+        let start = Instant::now();
+        let outcome = run_solver(&command, 1, 1, String::new(), Duration::from_millis(200));
+        assert_eq!(outcome, Outcome::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let pid = std::fs::read_to_string(&pid_file).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(!Path::new("/proc").join(pid.trim()).exists());
+    }
+}
@@ -0,0 +1,333 @@
+//! A terminal viewer stepping through the simulation of a day:
+//! space runs or pauses, the arrows step and rewind, `+`/`-` change the speed.
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{Frame, Terminal};
+use std::collections::VecDeque;
+use std::io;
+use std::time::Duration;
+
+pub use ratatui::crossterm::event::KeyCode;
+pub use ratatui::layout::Rect;
+
+// states kept for rewinding, the oldest ones are dropped first
+const HISTORY: usize = 200;
+const MAX_SPEED: usize = 1024;
+const TICK: Duration = Duration::from_millis(100);
+const HELP: &str = "space run/pause  → step  ← rewind  +/- speed  q quit";
+
+/// A day's puzzle played one step at a time, drawn by its own panel
+pub trait Simulation {
+    fn title(&self) -> String;
+    /// Plays one step, false when the simulation is over and nothing changed
+    fn step(&mut self) -> bool;
+    /// Where the simulation is, e.g. "move 3 of 4"
+    fn status(&self) -> String;
+    /// The state drawn in `area`, one string per row
+    fn panel(&self, area: Rect) -> Vec<String>;
+    fn boxed_clone(&self) -> Box<dyn Simulation>;
+}
+
+/// Steps a simulation, remembering the states it went through
+pub struct Player {
+    history: VecDeque<Box<dyn Simulation>>,
+    steps: usize,
+    running: bool,
+    finished: bool,
+    // steps played per tick while running
+    speed: usize,
+}
+
+impl Player {
+    pub fn new(simulation: Box<dyn Simulation>) -> Self {
+        Player {
+            history: VecDeque::from([simulation]),
+            steps: 0,
+            running: false,
+            finished: false,
+            speed: 1,
+        }
+    }
+
+    pub fn current(&self) -> &dyn Simulation {
+        self.history.back().unwrap().as_ref()
+    }
+
+    pub fn step(&mut self) {
+        let mut next = self.current().boxed_clone();
+        if !next.step() {
+            self.finished = true;
+            self.running = false;
+            return;
+        }
+        self.history.push_back(next);
+        if self.history.len() > HISTORY {
+            self.history.pop_front();
+        }
+        self.steps += 1;
+    }
+
+    /// Goes back one step, as far as the history goes
+    pub fn rewind(&mut self) {
+        if self.history.len() > 1 {
+            self.history.pop_back();
+            self.steps -= 1;
+            self.finished = false;
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.running = !self.running && !self.finished;
+    }
+
+    pub fn faster(&mut self) {
+        self.speed = (self.speed * 2).min(MAX_SPEED);
+    }
+
+    pub fn slower(&mut self) {
+        self.speed = (self.speed / 2).max(1);
+    }
+
+    pub fn tick(&mut self) {
+        for _ in 0..self.speed {
+            if !self.running {
+                break;
+            }
+            self.step();
+        }
+    }
+
+    fn state(&self) -> &'static str {
+        if self.finished {
+            "finished"
+        } else if self.running {
+            "running"
+        } else {
+            "paused"
+        }
+    }
+
+    /// Applies a key, false to quit
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.toggle(),
+            KeyCode::Right | KeyCode::Char('n') => {
+                self.running = false;
+                self.step();
+            }
+            KeyCode::Left | KeyCode::Char('b') => {
+                self.running = false;
+                self.rewind();
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => self.faster(),
+            KeyCode::Char('-') => self.slower(),
+            _ => {}
+        }
+        true
+    }
+}
+
+/// The title and player state, the panel of the simulation, then the keys
+pub fn draw(frame: &mut Frame, player: &Player) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let simulation = player.current();
+    frame.render_widget(
+        Paragraph::new(format!(
+            "{}  step {}  {}  x{}",
+            simulation.title(),
+            player.steps,
+            player.state(),
+            player.speed
+        )),
+        header,
+    );
+    let block = Block::bordered().title(simulation.status());
+    let lines = simulation.panel(block.inner(body));
+    frame.render_widget(Paragraph::new(lines.join("\n")).block(block), body);
+    frame.render_widget(Paragraph::new(HELP), footer);
+}
+
+/// Takes over the terminal until the viewer is quit
+pub fn run_viewer(simulation: Box<dyn Simulation>) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let mut player = Player::new(simulation);
+    let result = loop {
+        if let Err(e) = terminal.draw(|frame| draw(frame, &player)) {
+            break Err(e);
+        }
+        match event::poll(TICK) {
+            Ok(true) => match event::read() {
+                Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                    if !player.handle_key(key.code) {
+                        break Ok(());
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => break Err(e),
+            },
+            Ok(false) => player.tick(),
+            Err(e) => break Err(e),
+        }
+    };
+    ratatui::restore();
+    result
+}
+
+/// Rows of a rendered buffer, trailing spaces removed
+pub fn screen(buffer: &Buffer) -> Vec<String> {
+    let width = buffer.area.width as usize;
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| {
+            row.iter()
+                .map(|c| c.symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+/// The player drawn on a `TestBackend` of that size, as `screen` rows
+pub fn render(player: &Player, width: u16, height: u16) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|frame| draw(frame, player)).unwrap();
+    screen(terminal.backend().buffer())
+}
+
+#[cfg(test)]
+mod viewer_test {
+    use super::*;
+
+    // counts up to a limit
+    #[derive(Clone)]
+    struct Counter {
+        value: usize,
+        limit: usize,
+    }
+
+    impl Simulation for Counter {
+        fn title(&self) -> String {
+            "Counter".to_string()
+        }
+
+        fn step(&mut self) -> bool {
+            if self.value == self.limit {
+                return false;
+            }
+            self.value += 1;
+            true
+        }
+
+        fn status(&self) -> String {
+            format!("{} of {}", self.value, self.limit)
+        }
+
+        fn panel(&self, area: Rect) -> Vec<String> {
+            vec!["#".repeat(self.value.min(area.width as usize))]
+        }
+
+        fn boxed_clone(&self) -> Box<dyn Simulation> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn counter(limit: usize) -> Player {
+        Player::new(Box::new(Counter { value: 0, limit }))
+    }
+
+    #[test]
+    fn it_can_step_and_rewind() {
+        let mut player = counter(3);
+        player.step();
+        player.step();
+        assert_eq!(player.current().status(), "2 of 3");
+        player.rewind();
+        assert_eq!(player.current().status(), "1 of 3");
+        player.rewind();
+        player.rewind();
+        assert_eq!(player.steps, 0);
+        assert_eq!(player.current().status(), "0 of 3");
+
+        for _ in 0..5 {
+            player.step();
+        }
+        assert_eq!(player.steps, 3);
+        assert_eq!(player.state(), "finished");
+        player.toggle();
+        assert_eq!(player.state(), "finished");
+        player.rewind();
+        assert_eq!(player.state(), "paused");
+    }
+
+    #[test]
+    fn it_runs_at_its_speed() {
+        let mut player = counter(100);
+        player.tick();
+        assert_eq!(player.steps, 0);
+        assert!(player.handle_key(KeyCode::Char(' ')));
+        player.tick();
+        assert_eq!(player.steps, 1);
+        player.handle_key(KeyCode::Char('+'));
+        player.handle_key(KeyCode::Char('+'));
+        player.tick();
+        assert_eq!(player.steps, 5);
+        player.handle_key(KeyCode::Char('-'));
+        player.tick();
+        assert_eq!(player.steps, 7);
+        // stepping by hand pauses
+        player.handle_key(KeyCode::Right);
+        player.tick();
+        assert_eq!(player.steps, 8);
+        player.handle_key(KeyCode::Left);
+        assert_eq!(player.steps, 7);
+        assert!(!player.handle_key(KeyCode::Char('q')));
+
+        let mut player = counter(2);
+        player.toggle();
+        player.faster();
+        player.faster();
+        player.tick();
+        assert_eq!(player.steps, 2);
+        assert_eq!(player.state(), "finished");
+    }
+
+    #[test]
+    fn it_forgets_the_oldest_states() {
+        let mut player = counter(HISTORY + 10);
+        for _ in 0..HISTORY + 10 {
+            player.step();
+        }
+        for _ in 0..HISTORY + 10 {
+            player.rewind();
+        }
+        assert_eq!(player.steps, 11);
+        assert_eq!(player.current().status(), format!("11 of {}", HISTORY + 10));
+    }
+
+    #[test]
+    fn it_draws_the_player() {
+        let mut player = counter(3);
+        player.step();
+        player.step();
+        let rows = render(&player, 60, 5);
+        assert_eq!(rows[0], "Counter  step 2  paused  x1");
+        assert!(rows[1].starts_with("┌2 of 3"));
+        assert_eq!(
+            rows[2],
+            "│##                                                        │"
+        );
+        assert_eq!(rows[4], HELP);
+    }
+}
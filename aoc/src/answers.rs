@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// accepted answers, by (day, part), stored in `answers.tsv` as "day<TAB>part<TAB>answer"
+pub(crate) type Answers = BTreeMap<(u8, u8), String>;
+
+pub(crate) fn answers_path(root: &Path) -> PathBuf {
+    root.join("answers.tsv")
+}
+
+pub(crate) fn read_answers(root: &Path) -> io::Result<Answers> {
+    let content = match fs::read_to_string(answers_path(root)) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::new()),
+        Err(e) => return Err(e),
+    };
+    let mut answers = Answers::new();
+    for line in content.lines() {
+        let cols: Vec<&str> = line.splitn(3, '\t').collect();
+        if let [day, part, answer] = cols.as_slice() {
+            if let (Ok(day), Ok(part)) = (day.parse::<u8>(), part.parse::<u8>()) {
+                answers.insert((day, part), answer.to_string());
+            }
+        }
+    }
+    Ok(answers)
+}
+
+// the file is rewritten sorted, a new answer replaces the previous one
+#[cfg(any(feature = "network", test))]
+pub(crate) fn store_answer(root: &Path, day: u8, part: u8, answer: &str) -> io::Result<()> {
+    let mut answers = read_answers(root)?;
+    answers.insert((day, part), answer.to_string());
+    let content: String = answers
+        .iter()
+        .map(|((day, part), answer)| format!("{}\t{}\t{}\n", day, part, answer))
+        .collect();
+    fs::write(answers_path(root), content)
+}
+
+#[cfg(test)]
+mod answers_test {
+    use super::*;
+
+    #[test]
+    fn it_reads_missing_file_as_empty() {
+        let root = tempfile::tempdir().unwrap();
+        assert_eq!(read_answers(root.path()).unwrap(), Answers::new());
+    }
+
+    #[test]
+    fn it_can_store_answers() {
+        let root = tempfile::tempdir().unwrap();
+        store_answer(root.path(), 14, 1, "24").unwrap();
+        store_answer(root.path(), 5, 2, "MCD").unwrap();
+        store_answer(root.path(), 14, 1, "25").unwrap();
+
+        let content = fs::read_to_string(answers_path(root.path())).unwrap();
+        assert_eq!(content, "5\t2\tMCD\n14\t1\t25\n");
+        let answers = read_answers(root.path()).unwrap();
+        assert_eq!(answers.get(&(14, 1)), Some(&"25".to_string()));
+        assert_eq!(answers.get(&(14, 2)), None);
+    }
+}
@@ -0,0 +1,73 @@
+use advent_serve::SolverService;
+use clap::Parser;
+use std::io::{self, Read};
+use std::panic;
+use std::process::exit;
+use std::time::Duration;
+
+#[path = "../solvers.rs"]
+mod solvers;
+
+#[derive(Parser)]
+#[command(
+    name = "advent-serve",
+    about = "Answer POST /api/2022/day/N/part/P with the input as body, in JSON"
+)]
+struct Cli {
+    #[arg(long, default_value = "127.0.0.1:8022")]
+    address: String,
+    /// Seconds given to a solver before answering with a timeout
+    #[arg(long, default_value_t = 10)]
+    timeout: u64,
+    /// Print the answer of a part for the input on stdin, what the service runs for each request
+    #[arg(long, num_args = 2, value_names = ["DAY", "PART"], hide = true)]
+    solve: Option<Vec<u8>>,
+}
+
+// a panic exits with code 101, its message alone on stderr is what the service answers
+fn solve(day: u8, part: u8) {
+    let solvers = solvers::rust_solvers();
+    let Some((_, parts)) = solvers.iter().find(|(d, _)| *d == day) else {
+        eprintln!("Day {} has no solver", day);
+        exit(2);
+    };
+    let Some(solver) = part.checked_sub(1).and_then(|i| parts.get(i as usize)) else {
+        eprintln!("Part {} does not exist", part);
+        exit(2);
+    };
+    let mut input = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Could not read the input: {}", e);
+        exit(1);
+    }
+    panic::set_hook(Box::new(|info| {
+        eprintln!("{}", info.payload_as_str().unwrap_or("panicked"));
+    }));
+    println!("{}", solver(input));
+}
+
+fn main() {
+    let cli = Cli::parse();
+    if let Some(args) = cli.solve {
+        solve(args[0], args[1]);
+        return;
+    }
+    let command = match std::env::current_exe() {
+        Ok(exe) => vec![exe.to_string_lossy().to_string(), "--solve".to_string()],
+        Err(e) => {
+            eprintln!("Could not find the advent-serve binary: {}", e);
+            exit(1);
+        }
+    };
+    let days = solvers::rust_solvers()
+        .iter()
+        .map(|(day, _)| *day)
+        .collect();
+    let service = SolverService::new(command, days, Duration::from_secs(cli.timeout));
+    let server = service.serve(&cli.address);
+    println!(
+        "Serving the solvers on {}/api/2022/day/N/part/P",
+        server.base_url
+    );
+    server.join();
+}
@@ -0,0 +1,324 @@
+use crate::clock::Clock;
+use crate::unlock::YEAR;
+use reqwest::blocking::Client;
+use reqwest::header;
+use reqwest::StatusCode;
+use std::time::Duration;
+
+pub(crate) fn read_cookie_value() {
+    dotenvy::from_filename("../.env").unwrap();
+}
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// can point to a mock server, see advent_common::mock_server
+pub(crate) fn base_url() -> String {
+    dotenvy::var("ADVENT_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+pub(crate) fn input_url(base_url: &str, day: u8) -> String {
+    format!("{}/{}/day/{}/input", base_url, YEAR, day)
+}
+
+pub(crate) fn statement_url(base_url: &str, day: u8) -> String {
+    format!("{}/{}/day/{}", base_url, YEAR, day)
+}
+
+pub(crate) fn answer_url(base_url: &str, day: u8) -> String {
+    format!("{}/{}/day/{}/answer", base_url, YEAR, day)
+}
+
+pub(crate) fn build_http_client(cookie_value: &str) -> Client {
+    let mut headers = header::HeaderMap::new();
+    headers.insert(
+        "Cookie",
+        header::HeaderValue::from_str(cookie_value).unwrap(),
+    );
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .default_headers(headers)
+        .build()
+        .unwrap()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum FetchError {
+    // the site answers 404 until the puzzle opens
+    NotYetUnlocked,
+    Status(u16),
+    Transport(String),
+    // answer page we don't know how to read
+    UnexpectedPage,
+    // outside the 25 days of the calendar
+    NoPuzzle(u8),
+}
+
+impl FetchError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            FetchError::NotYetUnlocked => true,
+            FetchError::Transport(_) => true,
+            FetchError::Status(code) => *code >= 500,
+            FetchError::UnexpectedPage => false,
+            FetchError::NoPuzzle(_) => false,
+        }
+    }
+}
+
+pub(crate) fn get_text(client: &Client, url: &str) -> Result<String, FetchError> {
+    let resp = client
+        .get(url)
+        .send()
+        .map_err(|e| FetchError::Transport(e.to_string()))?;
+    match resp.status() {
+        StatusCode::NOT_FOUND => Err(FetchError::NotYetUnlocked),
+        status if !status.is_success() => Err(FetchError::Status(status.as_u16())),
+        _ => resp
+            .text()
+            .map_err(|e| FetchError::Transport(e.to_string())),
+    }
+}
+
+pub(crate) fn post_answer(
+    client: &Client,
+    url: &str,
+    part: u8,
+    answer: &str,
+) -> Result<String, FetchError> {
+    let level = part.to_string();
+    let resp = client
+        .post(url)
+        .form(&[("level", level.as_str()), ("answer", answer)])
+        .send()
+        .map_err(|e| FetchError::Transport(e.to_string()))?;
+    if !resp.status().is_success() {
+        return Err(FetchError::Status(resp.status().as_u16()));
+    }
+    resp.text()
+        .map_err(|e| FetchError::Transport(e.to_string()))
+}
+
+pub(crate) trait AdventSite {
+    fn input(&self, day: u8) -> Result<String, FetchError>;
+    fn statement(&self, day: u8) -> Result<String, FetchError>;
+    fn answer(&self, day: u8, part: u8, answer: &str) -> Result<String, FetchError>;
+}
+
+pub(crate) struct HttpAdventSite {
+    client: Client,
+    base_url: String,
+}
+
+impl HttpAdventSite {
+    pub(crate) fn new(base_url: &str, cookie_value: &str) -> Self {
+        HttpAdventSite {
+            client: build_http_client(cookie_value),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub(crate) fn from_env() -> Self {
+        let cookie_value = dotenvy::var("ADVENT_COOKIE").unwrap();
+        HttpAdventSite::new(&base_url(), &cookie_value)
+    }
+}
+
+impl AdventSite for HttpAdventSite {
+    fn input(&self, day: u8) -> Result<String, FetchError> {
+        get_text(&self.client, &input_url(&self.base_url, day))
+    }
+
+    fn statement(&self, day: u8) -> Result<String, FetchError> {
+        get_text(&self.client, &statement_url(&self.base_url, day))
+    }
+
+    fn answer(&self, day: u8, part: u8, answer: &str) -> Result<String, FetchError> {
+        post_answer(&self.client, &answer_url(&self.base_url, day), part, answer)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) base_delay: Duration,
+    pub(crate) max_delay: Duration,
+    pub(crate) max_jitter: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 8,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_jitter: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    // exponential backoff, plus some jitter
+    // so we're not hitting the server on the exact same millisecond as everyone
+    fn delay_before(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        let jitter_ms = fastrand::u64(0..=self.max_jitter.as_millis() as u64);
+        backoff + Duration::from_millis(jitter_ms)
+    }
+}
+
+pub(crate) fn fetch_with_retries<F>(
+    clock: &dyn Clock,
+    policy: &RetryPolicy,
+    mut fetch: F,
+) -> Result<String, FetchError>
+where
+    F: FnMut() -> Result<String, FetchError>,
+{
+    let mut attempt = 1;
+    loop {
+        match fetch() {
+            Ok(text) => return Ok(text),
+            Err(e) if e.is_retryable() && attempt < policy.max_attempts => {
+                let delay = policy.delay_before(attempt);
+                println!(
+                    "Attempt {} failed ({:?}), retrying in {:?}",
+                    attempt, e, delay
+                );
+                clock.sleep(delay);
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod client_test {
+    use super::*;
+    use crate::clock::FakeClock;
+    use advent_common::mock_server::{MockAdventServer, RunningServer};
+    use chrono::{TimeZone, Utc};
+
+    fn fake_clock() -> FakeClock {
+        FakeClock::new(Utc.with_ymd_and_hms(2022, 12, 15, 5, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn it_can_build_urls() {
+        let base = DEFAULT_BASE_URL;
+        assert_eq!(
+            input_url(base, 15),
+            "https://adventofcode.com/2022/day/15/input"
+        );
+        assert_eq!(
+            statement_url(base, 15),
+            "https://adventofcode.com/2022/day/15"
+        );
+        assert_eq!(
+            answer_url(base, 15),
+            "https://adventofcode.com/2022/day/15/answer"
+        );
+        assert_eq!(
+            input_url("http://127.0.0.1:8080", 1),
+            "http://127.0.0.1:8080/2022/day/1/input"
+        );
+    }
+
+    fn mock_server() -> RunningServer {
+        let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        MockAdventServer::new(&fixtures).serve("127.0.0.1:0")
+    }
+
+    #[test]
+    fn it_can_fetch_from_mock_server() {
+        let server = mock_server();
+        let site = HttpAdventSite::new(&server.base_url, "session=abc");
+        let input = site.input(1).unwrap();
+        assert!(input.starts_with("1000\n2000\n3000\n"));
+        let statement = site.statement(1).unwrap();
+        assert!(statement.contains("Calorie Counting"));
+        assert_eq!(site.input(25), Err(FetchError::NotYetUnlocked));
+    }
+
+    #[test]
+    fn it_gets_400_without_cookie() {
+        let server = mock_server();
+        let site = HttpAdventSite::new(&server.base_url, "");
+        assert_eq!(site.input(1), Err(FetchError::Status(400)));
+    }
+
+    #[test]
+    fn it_can_post_answer_to_mock_server() {
+        let server = mock_server();
+        let site = HttpAdventSite::new(&server.base_url, "session=abc");
+        let page = site.answer(1, 1, "24000").unwrap();
+        assert!(page.contains("That's the right answer"));
+        let page = site.answer(1, 2, "1").unwrap();
+        assert!(page.contains("too recently"));
+    }
+
+    #[test]
+    fn it_retries_until_unlocked() {
+        let clock = fake_clock();
+        let policy = RetryPolicy::default();
+        let mut calls = 0;
+        let result = fetch_with_retries(&clock, &policy, || {
+            calls += 1;
+            if calls < 3 {
+                Err(FetchError::NotYetUnlocked)
+            } else {
+                Ok("1000\n2000\n".to_string())
+            }
+        });
+        assert_eq!(result, Ok("1000\n2000\n".to_string()));
+        assert_eq!(calls, 3);
+        // 1s then 2s, each with at most 500ms of jitter
+        let slept = clock.total_slept();
+        assert!(slept >= Duration::from_secs(3));
+        assert!(slept <= Duration::from_secs(4));
+    }
+
+    #[test]
+    fn it_gives_up_after_max_attempts() {
+        let clock = fake_clock();
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            ..RetryPolicy::default()
+        };
+        let mut calls = 0;
+        let result = fetch_with_retries(&clock, &policy, || {
+            calls += 1;
+            Err(FetchError::NotYetUnlocked)
+        });
+        assert_eq!(result, Err(FetchError::NotYetUnlocked));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn it_does_not_retry_client_errors() {
+        let clock = fake_clock();
+        let policy = RetryPolicy::default();
+        let mut calls = 0;
+        let result = fetch_with_retries(&clock, &policy, || {
+            calls += 1;
+            Err(FetchError::Status(400))
+        });
+        assert_eq!(result, Err(FetchError::Status(400)));
+        assert_eq!(calls, 1);
+        assert_eq!(clock.total_slept(), Duration::ZERO);
+    }
+
+    #[test]
+    fn it_caps_the_backoff() {
+        let policy = RetryPolicy {
+            max_jitter: Duration::ZERO,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.delay_before(1), Duration::from_secs(1));
+        assert_eq!(policy.delay_before(3), Duration::from_secs(4));
+        assert_eq!(policy.delay_before(10), Duration::from_secs(30));
+    }
+}
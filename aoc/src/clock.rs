@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+#[cfg(test)]
+use std::cell::Cell;
+#[cfg(any(feature = "network", test))]
+use std::time::Duration;
+
+// everything that waits goes through a clock,
+// so tests don't have to wait for midnight
+pub(crate) trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    #[cfg(any(feature = "network", test))]
+    fn sleep(&self, duration: Duration);
+}
+
+pub(crate) struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    #[cfg(any(feature = "network", test))]
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+// clock that jumps forward instead of sleeping
+#[cfg(test)]
+pub(crate) struct FakeClock {
+    current: Cell<DateTime<Utc>>,
+    slept: Cell<Duration>,
+}
+
+#[cfg(test)]
+impl FakeClock {
+    pub(crate) fn new(start: DateTime<Utc>) -> Self {
+        FakeClock {
+            current: Cell::new(start),
+            slept: Cell::new(Duration::ZERO),
+        }
+    }
+
+    pub(crate) fn total_slept(&self) -> Duration {
+        self.slept.get()
+    }
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.current.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        let step = chrono::Duration::from_std(duration).unwrap();
+        self.current.set(self.current.get() + step);
+        self.slept.set(self.slept.get() + duration);
+    }
+}
+
+#[cfg(test)]
+mod clock_test {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn it_can_jump_forward() {
+        let start = Utc.with_ymd_and_hms(2022, 12, 1, 4, 59, 0).unwrap();
+        let clock = FakeClock::new(start);
+        clock.sleep(Duration::from_secs(60));
+        assert_eq!(
+            clock.now(),
+            Utc.with_ymd_and_hms(2022, 12, 1, 5, 0, 0).unwrap()
+        );
+        assert_eq!(clock.total_slept(), Duration::from_secs(60));
+    }
+}
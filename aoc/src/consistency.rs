@@ -0,0 +1,441 @@
+use crate::scaffold::{cached_input_path, day_dirname};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+// answers of both parts, or why there are none (crash, bad output)
+pub(crate) type Outcome = Result<Vec<String>, String>;
+
+// one way of solving a day, e.g. the Rust crate or the Python package
+pub(crate) trait Implementation {
+    fn name(&self) -> &str;
+    fn solve(&self, input: &str) -> Outcome;
+}
+
+// how a program prints its answers, other lines are ignored
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum AnswerFormat {
+    // "First solution: 24000" / "Second solution: \"MCD\"", the day crates
+    Labelled,
+    // exactly "<part> <answer>" per line, e.g. "1 13", the Python packages
+    Numbered,
+}
+
+// a program reading the input on stdin and printing its answers
+pub(crate) struct CommandImplementation {
+    pub(crate) name: String,
+    pub(crate) program: String,
+    pub(crate) args: Vec<String>,
+    pub(crate) dir: PathBuf,
+    pub(crate) format: AnswerFormat,
+}
+
+impl Implementation for CommandImplementation {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn solve(&self, input: &str) -> Outcome {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .current_dir(&self.dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("could not start {}: {}", self.program, e))?;
+        // a program failing early may not read everything
+        let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+        let output = child.wait_with_output().map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let last_line = stderr.lines().last().unwrap_or("").to_string();
+            return Err(format!("{}: {}", output.status, last_line));
+        }
+        parse_answers(&String::from_utf8_lossy(&output.stdout), self.format)
+    }
+}
+
+// the (part, answer) of a "<part> <answer>" line, e.g. "2 140"
+fn numbered_answer(line: &str) -> Option<(usize, &str)> {
+    let (part, answer) = line.split_once(' ')?;
+    let part = match part {
+        "1" => 1,
+        "2" => 2,
+        _ => return None,
+    };
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((part, answer))
+}
+
+// the answers printed in `format`, in part order
+pub(crate) fn parse_answers(stdout: &str, format: AnswerFormat) -> Outcome {
+    let answers: Vec<String> = match format {
+        AnswerFormat::Labelled => stdout
+            .lines()
+            .filter_map(|line| {
+                line.strip_prefix("First solution: ")
+                    .or_else(|| line.strip_prefix("Second solution: "))
+            })
+            .map(|answer| answer.trim().trim_matches('"').to_string())
+            .collect(),
+        AnswerFormat::Numbered => {
+            let mut parts: [Option<String>; 2] = [None, None];
+            for (part, answer) in stdout.lines().filter_map(numbered_answer) {
+                if parts[part - 1].replace(answer.to_string()).is_some() {
+                    return Err(format!("part {} answered twice", part));
+                }
+            }
+            match parts {
+                [None, Some(_)] => return Err("no answer for part 1".to_string()),
+                [first, second] => first.into_iter().chain(second).collect(),
+            }
+        }
+    };
+    if answers.is_empty() {
+        Err("no answer in the output".to_string())
+    } else {
+        Ok(answers)
+    }
+}
+
+// `cargo run -- --stdin` in day-NN
+pub(crate) fn rust_implementation(root: &Path, day: u8) -> Option<CommandImplementation> {
+    let dir = root.join(day_dirname(day));
+    if !dir.join("Cargo.toml").exists() {
+        return None;
+    }
+    Some(CommandImplementation {
+        name: "rust".to_string(),
+        program: "cargo".to_string(),
+        args: ["run", "--release", "--quiet", "--", "--stdin"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect(),
+        dir,
+        format: AnswerFormat::Labelled,
+    })
+}
+
+// the interpreter and its arguments from ADVENT_PYTHON, poetry when unset
+fn python_command(env_value: Option<String>) -> Result<Vec<String>, String> {
+    let python = env_value.unwrap_or_else(|| "poetry run python".to_string());
+    let words: Vec<String> = python.split_whitespace().map(|x| x.to_string()).collect();
+    if words.is_empty() {
+        return Err(format!("ADVENT_PYTHON names no interpreter: {:?}", python));
+    }
+    Ok(words)
+}
+
+// `python -m dayNNpy.main --stdin` in day-NN-py, through poetry
+// unless ADVENT_PYTHON names another interpreter, an error if it is blank
+pub(crate) fn python_implementation(
+    root: &Path,
+    day: u8,
+) -> Result<Option<CommandImplementation>, String> {
+    let dir = root.join(format!("{}-py", day_dirname(day)));
+    if !dir.join("pyproject.toml").exists() {
+        return Ok(None);
+    }
+    let mut args = python_command(std::env::var("ADVENT_PYTHON").ok())?;
+    let program = args.remove(0);
+    args.extend([
+        "-m".to_string(),
+        format!("day{:02}py.main", day),
+        "--stdin".to_string(),
+    ]);
+    Ok(Some(CommandImplementation {
+        name: "python".to_string(),
+        program,
+        args,
+        dir,
+        format: AnswerFormat::Numbered,
+    }))
+}
+
+// two crashes say nothing about the answers, any other difference does
+pub(crate) fn diverges(outcomes: &[Outcome]) -> bool {
+    let answers: Vec<&Vec<String>> = outcomes.iter().filter_map(|o| o.as_ref().ok()).collect();
+    match answers.first() {
+        None => false,
+        Some(first) => answers.len() != outcomes.len() || answers.iter().any(|a| a != first),
+    }
+}
+
+fn solve_all(implementations: &[&dyn Implementation], input: &str) -> Vec<Outcome> {
+    implementations.iter().map(|i| i.solve(input)).collect()
+}
+
+// the input cut into the pieces the minimizer removes:
+// blocks separated by an empty line if any (day 13 pairs), lines otherwise
+fn split_units(input: &str) -> (Vec<String>, &'static str) {
+    let trimmed = input.trim_end_matches('\n');
+    if trimmed.contains("\n\n") {
+        (
+            trimmed.split("\n\n").map(|x| x.to_string()).collect(),
+            "\n\n",
+        )
+    } else {
+        (trimmed.lines().map(|x| x.to_string()).collect(), "\n")
+    }
+}
+
+fn join_units(units: &[String], separator: &str) -> String {
+    format!("{}\n", units.join(separator))
+}
+
+// removes chunks of units, halving their size, as long as the input still diverges
+pub(crate) fn minimize(input: &str, mut still_diverges: impl FnMut(&str) -> bool) -> String {
+    let (mut units, separator) = split_units(input);
+    let mut chunk = (units.len() / 2).max(1);
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+        while start < units.len() && units.len() > 1 {
+            let end = (start + chunk).min(units.len());
+            let candidate: Vec<String> = [&units[..start], &units[end..]].concat();
+            if !candidate.is_empty() && still_diverges(&join_units(&candidate, separator)) {
+                units = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+        if chunk == 1 && !removed {
+            break;
+        }
+        if !removed {
+            chunk = (chunk / 2).max(1);
+        }
+    }
+    join_units(&units, separator)
+}
+
+// random packet pairs, nested lists of small integers
+fn random_packet(rng: &mut fastrand::Rng, depth: u32) -> String {
+    let items: Vec<String> = (0..rng.usize(0..5))
+        .map(|_| {
+            if depth < 3 && rng.bool() {
+                random_packet(rng, depth + 1)
+            } else {
+                rng.u8(0..11).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+// stress inputs of the days that have a generator
+pub(crate) fn generate_input(day: u8, rng: &mut fastrand::Rng) -> Option<String> {
+    match day {
+        13 => {
+            let pairs: Vec<String> = (0..rng.usize(1..10))
+                .map(|_| format!("{}\n{}", random_packet(rng, 0), random_packet(rng, 0)))
+                .collect();
+            Some(format!("{}\n", pairs.join("\n\n")))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Divergence {
+    pub(crate) case: String,
+    pub(crate) minimized: String,
+    // name and outcome of each implementation on the minimized input
+    pub(crate) outcomes: Vec<(String, Outcome)>,
+}
+
+// each input on which the implementations disagree, minimized
+pub(crate) fn check_inputs(
+    implementations: &[&dyn Implementation],
+    cases: Vec<(String, String)>,
+) -> Vec<Divergence> {
+    let mut divergences = vec![];
+    for (case, input) in cases {
+        if !diverges(&solve_all(implementations, &input)) {
+            continue;
+        }
+        let minimized = minimize(&input, |candidate| {
+            diverges(&solve_all(implementations, candidate))
+        });
+        let outcomes = implementations
+            .iter()
+            .map(|i| (i.name().to_string(), i.solve(&minimized)))
+            .collect();
+        divergences.push(Divergence {
+            case,
+            minimized,
+            outcomes,
+        });
+    }
+    divergences
+}
+
+// the cached input then `stress` generated ones, the same seed gives the same inputs
+pub(crate) fn check_cases(root: &Path, day: u8, stress: usize, seed: u64) -> Vec<(String, String)> {
+    let mut cases = vec![];
+    if let Ok(input) = fs::read_to_string(cached_input_path(root, day)) {
+        cases.push(("cached input".to_string(), input));
+    }
+    let mut rng = fastrand::Rng::with_seed(seed);
+    for i in 0..stress {
+        match generate_input(day, &mut rng) {
+            Some(input) => cases.push((format!("stress #{} (seed {})", i, seed), input)),
+            None => break,
+        }
+    }
+    cases
+}
+
+#[cfg(test)]
+mod consistency_test {
+    use super::*;
+
+    // counts the pairs, and gets it wrong once a packet holds a 7
+    struct Fake {
+        name: &'static str,
+        buggy: bool,
+    }
+
+    impl Implementation for Fake {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn solve(&self, input: &str) -> Outcome {
+            let pairs = input.trim_end().split("\n\n").count();
+            if self.buggy && input.contains('7') {
+                return Ok(vec![(pairs + 1).to_string(), "0".to_string()]);
+            }
+            Ok(vec![pairs.to_string(), "0".to_string()])
+        }
+    }
+
+    #[test]
+    fn it_can_parse_answers() {
+        let labelled = "Size: (5, 5)\nFirst solution: 21\nSecond solution: \"MCD\"\n";
+        assert_eq!(
+            parse_answers(labelled, AnswerFormat::Labelled),
+            Ok(vec!["21".to_string(), "MCD".to_string()])
+        );
+        assert_eq!(
+            parse_answers("1 13\n2 140\n", AnswerFormat::Numbered),
+            Ok(vec!["13".to_string(), "140".to_string()])
+        );
+        assert!(parse_answers("Traceback\n", AnswerFormat::Numbered).is_err());
+        // each implementation is only read in its own format
+        assert!(parse_answers("1 13\n", AnswerFormat::Labelled).is_err());
+        assert!(parse_answers(labelled, AnswerFormat::Numbered).is_err());
+        // debug output that only looks like an answer
+        assert_eq!(
+            parse_answers("1 row done\n2 140\n1 13\n", AnswerFormat::Numbered),
+            Ok(vec!["13".to_string(), "140".to_string()])
+        );
+        assert_eq!(
+            parse_answers("2 140\n", AnswerFormat::Numbered),
+            Err("no answer for part 1".to_string())
+        );
+        assert!(parse_answers("1 13\n1 14\n", AnswerFormat::Numbered).is_err());
+    }
+
+    #[test]
+    fn it_reads_the_python_command() {
+        assert_eq!(
+            python_command(None).unwrap(),
+            vec!["poetry", "run", "python"]
+        );
+        assert_eq!(
+            python_command(Some(" python3  -X dev ".to_string())).unwrap(),
+            vec!["python3", "-X", "dev"]
+        );
+        assert!(python_command(Some(String::new())).is_err());
+        assert!(python_command(Some(" \t".to_string())).is_err());
+    }
+
+    #[test]
+    fn it_only_diverges_on_different_answers() {
+        let a: Outcome = Ok(vec!["1".to_string()]);
+        let b: Outcome = Ok(vec!["2".to_string()]);
+        let crash: Outcome = Err("exit status: 1".to_string());
+        assert!(!diverges(&[a.clone(), a.clone()]));
+        assert!(diverges(&[a.clone(), b]));
+        assert!(diverges(&[a, crash.clone()]));
+        assert!(!diverges(&[crash.clone(), crash]));
+    }
+
+    #[test]
+    fn it_can_minimize_blocks_and_lines() {
+        let input = "[1]\n[2]\n\n[3]\n[7]\n\n[4]\n[5]\n\n[6]\n[8]\n";
+        assert_eq!(minimize(input, |x| x.contains('7')), "[3]\n[7]\n");
+        let lines = "a\nb\nc\nd\ne\nf\n";
+        assert_eq!(
+            minimize(lines, |x| x.contains('b') && x.contains('e')),
+            "b\ne\n"
+        );
+    }
+
+    #[test]
+    fn it_reports_minimized_divergences() {
+        let good = Fake {
+            name: "rust",
+            buggy: false,
+        };
+        let bad = Fake {
+            name: "python",
+            buggy: true,
+        };
+        let cases = vec![
+            ("same".to_string(), "[1]\n[2]\n".to_string()),
+            (
+                "different".to_string(),
+                "[1]\n[2]\n\n[[7]]\n[3]\n\n[4]\n[5]\n".to_string(),
+            ),
+        ];
+        let divergences = check_inputs(&[&good, &bad], cases);
+        assert_eq!(divergences.len(), 1);
+        assert_eq!(divergences[0].case, "different");
+        assert_eq!(divergences[0].minimized, "[[7]]\n[3]\n");
+        assert_eq!(
+            divergences[0].outcomes[1],
+            (
+                "python".to_string(),
+                Ok(vec!["2".to_string(), "0".to_string()])
+            )
+        );
+    }
+
+    #[test]
+    fn it_can_run_commands() {
+        let line_count = CommandImplementation {
+            name: "wc".to_string(),
+            program: "sh".to_string(),
+            args: vec!["-c".to_string(), "echo \"1 $(wc -l)\"".to_string()],
+            dir: PathBuf::from("."),
+            format: AnswerFormat::Numbered,
+        };
+        assert_eq!(line_count.solve("a\nb\n"), Ok(vec!["2".to_string()]));
+        let failing = CommandImplementation {
+            name: "false".to_string(),
+            program: "sh".to_string(),
+            args: vec!["-c".to_string(), "echo oops >&2; exit 3".to_string()],
+            dir: PathBuf::from("."),
+            format: AnswerFormat::Numbered,
+        };
+        assert!(failing.solve("").unwrap_err().ends_with("oops"));
+    }
+
+    #[test]
+    fn it_generates_the_same_stress_inputs_from_a_seed() {
+        let root = tempfile::tempdir().unwrap();
+        let cases = check_cases(root.path(), 13, 3, 42);
+        assert_eq!(cases.len(), 3);
+        assert_eq!(cases, check_cases(root.path(), 13, 3, 42));
+        assert!(cases[0].1.starts_with('['));
+        // no cached input and no generator
+        assert!(check_cases(root.path(), 1, 3, 42).is_empty());
+    }
+}
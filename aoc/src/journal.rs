@@ -0,0 +1,327 @@
+use crate::clock::Clock;
+use crate::scaffold::day_dirname;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // answered too recently, the site didn't check it
+    RateLimited,
+    // level already solved or locked
+    WrongLevel,
+}
+
+impl Verdict {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate_limited",
+            Verdict::WrongLevel => "wrong_level",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(item: &str) -> Result<Self, Self::Err> {
+        match item {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "rate_limited" => Ok(Verdict::RateLimited),
+            "wrong_level" => Ok(Verdict::WrongLevel),
+            _ => Err(format!(
+                "unknown verdict {:?}, expected correct, too_high, too_low, wrong, rate_limited or wrong_level",
+                item
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) enum JournalEvent {
+    InputFetched,
+    FirstTestGreen,
+    Submission { part: u8, verdict: Verdict },
+    StarEarned { part: u8 },
+}
+
+impl JournalEvent {
+    fn encode(&self) -> String {
+        match self {
+            JournalEvent::InputFetched => "input_fetched".to_string(),
+            JournalEvent::FirstTestGreen => "test_green".to_string(),
+            JournalEvent::Submission { part, verdict } => {
+                format!("submission {} {}", part, verdict.as_str())
+            }
+            JournalEvent::StarEarned { part } => format!("star {}", part),
+        }
+    }
+}
+
+fn parse_part(part: &str) -> Result<u8, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("unknown part {:?}, expected 1 or 2", part)),
+    }
+}
+
+// also the value of `aoc journal add`, so a typo is an error rather than a panic
+impl FromStr for JournalEvent {
+    type Err = String;
+
+    fn from_str(item: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = item.split_whitespace().collect();
+        match words.as_slice() {
+            ["input_fetched"] => Ok(JournalEvent::InputFetched),
+            ["test_green"] => Ok(JournalEvent::FirstTestGreen),
+            ["submission", part, verdict] => Ok(JournalEvent::Submission {
+                part: parse_part(part)?,
+                verdict: verdict.parse()?,
+            }),
+            ["star", part] => Ok(JournalEvent::StarEarned {
+                part: parse_part(part)?,
+            }),
+            _ => Err(format!(
+                "unknown journal event {:?}, expected input_fetched, test_green, \"submission PART VERDICT\" or \"star PART\"",
+                item
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct JournalEntry {
+    pub(crate) at: DateTime<Utc>,
+    pub(crate) day: u8,
+    pub(crate) event: JournalEvent,
+}
+
+// one entry per line: timestamp, day and event separated by tabs
+impl FromStr for JournalEntry {
+    type Err = String;
+
+    fn from_str(item: &str) -> Result<Self, Self::Err> {
+        let [at, day, event] = item.splitn(3, '\t').collect::<Vec<&str>>()[..] else {
+            return Err(format!(
+                "expected timestamp, day and event separated by tabs, got {:?}",
+                item
+            ));
+        };
+        let at = DateTime::parse_from_rfc3339(at)
+            .map_err(|e| format!("bad timestamp {:?}: {}", at, e))?
+            .with_timezone(&Utc);
+        let day = day
+            .parse::<u8>()
+            .map_err(|_| format!("bad day {:?}", day))?;
+        let event = event.parse()?;
+        Ok(JournalEntry { at, day, event })
+    }
+}
+
+impl JournalEntry {
+    fn encode(&self) -> String {
+        format!(
+            "{}\t{}\t{}",
+            self.at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            self.day,
+            self.event.encode()
+        )
+    }
+}
+
+pub(crate) fn journal_path(root: &Path) -> PathBuf {
+    root.join("journal.tsv")
+}
+
+// a line that cannot be read is an InvalidData error with its number, from 1
+pub(crate) fn read_journal(root: &Path) -> io::Result<Vec<JournalEntry>> {
+    let path = journal_path(root);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let content = fs::read_to_string(&path)?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(index, line)| {
+            line.parse().map_err(|e| {
+                let message = format!("{} line {}: {}", path.display(), index + 1, e);
+                io::Error::new(io::ErrorKind::InvalidData, message)
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn record(root: &Path, entry: JournalEntry) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(journal_path(root))?;
+    writeln!(file, "{}", entry.encode())
+}
+
+// only the first green test run of a day is interesting
+pub(crate) fn record_first_test_green(root: &Path, day: u8, at: DateTime<Utc>) -> io::Result<bool> {
+    let already_green = read_journal(root)?
+        .iter()
+        .any(|e| e.day == day && e.event == JournalEvent::FirstTestGreen);
+    if already_green {
+        return Ok(false);
+    }
+    record(
+        root,
+        JournalEntry {
+            at,
+            day,
+            event: JournalEvent::FirstTestGreen,
+        },
+    )?;
+    Ok(true)
+}
+
+// run the day tests, the first passing run goes into the journal
+pub(crate) fn test_and_record(clock: &dyn Clock, root: &Path, day: u8) -> bool {
+    let status = Command::new("cargo")
+        .arg("test")
+        .current_dir(root.join(day_dirname(day)))
+        .status()
+        .unwrap();
+    if status.success() {
+        record_first_test_green(root, day, clock.now()).unwrap();
+    }
+    status.success()
+}
+
+#[cfg(test)]
+mod journal_test {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn it_can_encode_and_parse_entries() {
+        let entry = JournalEntry {
+            at: Utc.with_ymd_and_hms(2022, 12, 14, 5, 31, 2).unwrap(),
+            day: 14,
+            event: JournalEvent::Submission {
+                part: 2,
+                verdict: Verdict::TooLow,
+            },
+        };
+        let line = entry.encode();
+        assert_eq!(line, "2022-12-14T05:31:02Z\t14\tsubmission 2 too_low");
+        assert_eq!(line.parse::<JournalEntry>(), Ok(entry));
+    }
+
+    #[test]
+    fn it_refuses_bad_entries() {
+        assert!("2022-12-14T05:31:02Z\t14"
+            .parse::<JournalEntry>()
+            .unwrap_err()
+            .contains("tabs"));
+        assert!("yesterday\t14\tstar 1"
+            .parse::<JournalEntry>()
+            .unwrap_err()
+            .contains("bad timestamp"));
+        assert!("2022-12-14T05:31:02Z\tx\tstar 1"
+            .parse::<JournalEntry>()
+            .unwrap_err()
+            .contains("bad day"));
+        assert!("2022-12-14T05:31:02Z\t14\tstar 9"
+            .parse::<JournalEntry>()
+            .unwrap_err()
+            .contains("unknown part"));
+    }
+
+    #[test]
+    fn it_can_parse_every_event() {
+        assert_eq!(
+            "input_fetched".parse::<JournalEvent>().unwrap(),
+            JournalEvent::InputFetched
+        );
+        assert_eq!(
+            "test_green".parse::<JournalEvent>().unwrap(),
+            JournalEvent::FirstTestGreen
+        );
+        assert_eq!(
+            "star 1".parse::<JournalEvent>().unwrap(),
+            JournalEvent::StarEarned { part: 1 }
+        );
+        assert_eq!(
+            "submission 1 rate_limited".parse::<JournalEvent>().unwrap(),
+            JournalEvent::Submission {
+                part: 1,
+                verdict: Verdict::RateLimited
+            }
+        );
+    }
+
+    #[test]
+    fn it_refuses_unknown_events() {
+        assert!("star"
+            .parse::<JournalEvent>()
+            .unwrap_err()
+            .contains("unknown journal event"));
+        assert!("star 3"
+            .parse::<JournalEvent>()
+            .unwrap_err()
+            .contains("unknown part"));
+        assert!("submission 1 too_big"
+            .parse::<JournalEvent>()
+            .unwrap_err()
+            .contains("unknown verdict \"too_big\""));
+        assert!("launched".parse::<JournalEvent>().is_err());
+    }
+
+    #[test]
+    fn it_can_append_to_journal() {
+        let root = tempfile::tempdir().unwrap();
+        assert_eq!(read_journal(root.path()).unwrap(), vec![]);
+        let first = JournalEntry {
+            at: Utc.with_ymd_and_hms(2022, 12, 14, 5, 0, 1).unwrap(),
+            day: 14,
+            event: JournalEvent::InputFetched,
+        };
+        let second = JournalEntry {
+            at: Utc.with_ymd_and_hms(2022, 12, 14, 5, 40, 0).unwrap(),
+            day: 14,
+            event: JournalEvent::StarEarned { part: 1 },
+        };
+        record(root.path(), first.clone()).unwrap();
+        record(root.path(), second.clone()).unwrap();
+        assert_eq!(read_journal(root.path()).unwrap(), vec![first, second]);
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(journal_path(root.path()))
+            .unwrap();
+        writeln!(file, "\nnot an entry").unwrap();
+        let error = read_journal(root.path()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("journal.tsv line 4: "));
+    }
+
+    #[test]
+    fn it_records_first_green_only_once() {
+        let root = tempfile::tempdir().unwrap();
+        let at = Utc.with_ymd_and_hms(2022, 12, 14, 5, 20, 0).unwrap();
+        assert!(record_first_test_green(root.path(), 14, at).unwrap());
+        assert!(!record_first_test_green(root.path(), 14, at).unwrap());
+        assert!(record_first_test_green(root.path(), 15, at).unwrap());
+        assert_eq!(read_journal(root.path()).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,326 @@
+use advent_common::mock_server::MockAdventServer;
+use chrono::Utc;
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+mod answers;
+#[cfg(feature = "network")]
+mod client;
+mod clock;
+mod consistency;
+mod journal;
+mod readme;
+mod runner;
+mod scaffold;
+mod simulations;
+mod solvers;
+#[cfg(feature = "network")]
+mod submit;
+mod trace_filter;
+#[cfg(feature = "network")]
+mod unlock;
+#[cfg(feature = "network")]
+mod wait;
+#[cfg(feature = "network")]
+use client::{read_cookie_value, HttpAdventSite, RetryPolicy};
+use clock::SystemClock;
+use consistency::{
+    check_cases, check_inputs, python_implementation, rust_implementation, Implementation,
+};
+use journal::{read_journal, record, test_and_record, JournalEntry, JournalEvent};
+use readme::refresh_readme;
+use runner::{format_table, registered_days, run_days};
+use simulations::simulation_for;
+#[cfg(feature = "network")]
+use submit::submit_and_record;
+use trace_filter::{parse_field, TraceFilter};
+#[cfg(feature = "network")]
+use wait::wait_and_fetch;
+
+// the tool is run from its own folder, like the day crates
+const REPO_ROOT: &str = "..";
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Tooling around the advent of code crates")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Wait for a puzzle to unlock, then fetch it and create its crate
+    #[cfg(feature = "network")]
+    Wait {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Submit an answer and log its verdict in the journal
+    #[cfg(feature = "network")]
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+        answer: String,
+    },
+    /// Run the tests of a day, the first green run is logged in the journal
+    Test {
+        #[arg(long)]
+        day: u8,
+    },
+    #[command(subcommand)]
+    Journal(JournalCommand),
+    /// Serve a fake adventofcode.com from fixture files, see ADVENT_BASE_URL
+    Mock {
+        #[arg(long, default_value = "fixtures")]
+        fixtures: PathBuf,
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        address: String,
+    },
+    /// Print the events of a `--trace` file matching all the filters
+    Trace {
+        file: PathBuf,
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long)]
+        event: Option<String>,
+        /// FIELD=VALUE, can be repeated
+        #[arg(long = "where", value_parser = parse_field)]
+        fields: Vec<(String, String)>,
+        /// Only print the number of matching events
+        #[arg(long)]
+        count: bool,
+    },
+    /// Solve the cached inputs of several days in parallel and check them against answers.tsv
+    Run {
+        /// Every day with a Rust crate, and day 13 through its Python package
+        #[arg(long)]
+        all: bool,
+        /// Can be repeated
+        #[arg(long)]
+        day: Vec<u8>,
+    },
+    /// Compare the Rust and Python solutions of a day on its cached input and random ones
+    Check {
+        #[arg(long)]
+        day: u8,
+        /// Number of generated inputs, for the days with a generator
+        #[arg(long, default_value_t = 100)]
+        stress: usize,
+        /// Seed of the generated inputs, random by default
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Step through the simulation of day 5, 9, 10, 11 or 14 in the terminal
+    View {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+        /// The cached input by default
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum JournalCommand {
+    /// Log an event by hand, e.g. "submission 1 too_high" or "star 2"
+    Add {
+        #[arg(long)]
+        day: u8,
+        event: JournalEvent,
+    },
+    /// Refresh the per-day stats blocks of README.md
+    Readme,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let root = Path::new(REPO_ROOT);
+    match cli.command {
+        #[cfg(feature = "network")]
+        Command::Wait { day } => {
+            read_cookie_value();
+            let site = HttpAdventSite::from_env();
+            let result = wait_and_fetch(&SystemClock, &site, &RetryPolicy::default(), root, day);
+            if let Err(e) = result {
+                eprintln!("Could not fetch day {}: {:?}", day, e);
+                std::process::exit(1);
+            }
+        }
+        #[cfg(feature = "network")]
+        Command::Submit { day, part, answer } => {
+            read_cookie_value();
+            let site = HttpAdventSite::from_env();
+            match submit_and_record(&SystemClock, &site, root, day, part, &answer) {
+                Ok(verdict) => println!("Day {} part {}: {:?}", day, part, verdict),
+                Err(e) => {
+                    eprintln!("Could not submit: {:?}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Test { day } => {
+            if !test_and_record(&SystemClock, root, day) {
+                std::process::exit(1);
+            }
+        }
+        Command::Journal(JournalCommand::Add { day, event }) => {
+            let entry = JournalEntry {
+                at: Utc::now(),
+                day,
+                event,
+            };
+            record(root, entry).unwrap();
+        }
+        Command::Journal(JournalCommand::Readme) => {
+            let readme_path = root.join("README.md");
+            let readme = fs::read_to_string(&readme_path).unwrap();
+            let entries = match read_journal(root) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    eprintln!("Could not read the journal: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            fs::write(&readme_path, refresh_readme(&readme, &entries)).unwrap();
+        }
+        Command::Mock { fixtures, address } => {
+            let server = MockAdventServer::new(&fixtures).serve(&address);
+            println!("Serving {:?} on {}", fixtures, server.base_url);
+            server.join();
+        }
+        Command::Trace {
+            file,
+            day,
+            event,
+            fields,
+            count,
+        } => {
+            let content = fs::read_to_string(&file).unwrap();
+            let filter = TraceFilter { day, event, fields };
+            let lines = filter.filter_lines(&content);
+            if count {
+                println!("{}", lines.len());
+            } else {
+                for line in lines {
+                    println!("{}", line);
+                }
+            }
+        }
+        Command::Run { all, day } => {
+            let registered = match registered_days(root) {
+                Ok(registered) => registered,
+                Err(message) => {
+                    eprintln!("{}", message);
+                    std::process::exit(2);
+                }
+            };
+            let days: Vec<_> = registered
+                .into_iter()
+                .filter(|d| all || day.contains(&d.day))
+                .collect();
+            if days.is_empty() {
+                eprintln!("Nothing to run, use --all or --day");
+                std::process::exit(1);
+            }
+            let start = Instant::now();
+            let reports = match run_days(&days, root) {
+                Ok(reports) => reports,
+                Err(e) => {
+                    eprintln!("Could not read the answers: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            print!("{}", format_table(&reports));
+            println!("Wall time: {:.1}ms", start.elapsed().as_secs_f64() * 1000.0);
+            if reports.iter().any(|r| r.is_failure()) {
+                std::process::exit(1);
+            }
+        }
+        Command::Check { day, stress, seed } => {
+            let python = match python_implementation(root, day) {
+                Ok(python) => python,
+                Err(message) => {
+                    eprintln!("{}", message);
+                    std::process::exit(2);
+                }
+            };
+            let (rust, python) = match (rust_implementation(root, day), python) {
+                (Some(rust), Some(python)) => (rust, python),
+                (rust, python) => {
+                    eprintln!(
+                        "Day {} needs both implementations (rust: {}, python: {})",
+                        day,
+                        rust.is_some(),
+                        python.is_some()
+                    );
+                    std::process::exit(1);
+                }
+            };
+            let seed = seed.unwrap_or_else(|| fastrand::u64(..));
+            let cases = check_cases(root, day, stress, seed);
+            println!(
+                "Checking {} inputs of day {}, seed {}",
+                cases.len(),
+                day,
+                seed
+            );
+            let implementations: [&dyn Implementation; 2] = [&rust, &python];
+            let divergences = check_inputs(&implementations, cases);
+            for (i, divergence) in divergences.iter().enumerate() {
+                let path =
+                    scaffold::cache_dir(root).join(format!("day-{:02}-divergence-{}.txt", day, i));
+                scaffold::write_cache(&path, &divergence.minimized).unwrap();
+                println!("\n{} diverges, reproducer in {:?}:", divergence.case, path);
+                print!("{}", divergence.minimized);
+                for (name, outcome) in divergence.outcomes.iter() {
+                    println!("  {}: {:?}", name, outcome);
+                }
+            }
+            if !divergences.is_empty() {
+                std::process::exit(1);
+            }
+            println!("No divergence");
+        }
+        Command::View { day, part, input } => {
+            let path = input.unwrap_or_else(|| scaffold::cached_input_path(root, day));
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    eprintln!("Could not read {:?}: {}", path, e);
+                    std::process::exit(1);
+                }
+            };
+            let Some(simulation) = simulation_for(day, part, content) else {
+                eprintln!("Day {} has no simulation to view", day);
+                std::process::exit(1);
+            };
+            advent_viewer::run_viewer(simulation).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod main_test {
+    use super::*;
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn it_refuses_days_outside_calendar_for_wait() {
+        assert!(Cli::try_parse_from(["aoc", "wait", "--day", "0"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "wait", "--day", "26"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "wait", "--day", "25"]).is_ok());
+    }
+
+    #[test]
+    fn it_refuses_unknown_journal_events() {
+        let add = |event: &str| Cli::try_parse_from(["aoc", "journal", "add", "--day", "1", event]);
+        assert!(add("submission 1 too_high").is_ok());
+        let error = add("submission 1 too_big").err().unwrap().to_string();
+        assert!(error.contains("unknown verdict"));
+    }
+}
@@ -0,0 +1,334 @@
+use crate::journal::{JournalEntry, JournalEvent, Verdict};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
+
+const BLOCK_END: &str = "<!-- /journal -->";
+
+fn block_start(day: u8) -> String {
+    format!("<!-- journal day {} -->", day)
+}
+
+fn format_elapsed(from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    let minutes = (to - from).num_minutes();
+    if minutes >= 60 {
+        format!("{}h{:02}mn", minutes / 60, minutes % 60)
+    } else {
+        format!("{}mn", minutes)
+    }
+}
+
+// "after 22mn" when we know when the input came in, the time of day otherwise
+fn format_moment(start: Option<DateTime<Utc>>, at: DateTime<Utc>) -> String {
+    match start {
+        Some(s) => format!("after {}", format_elapsed(s, at)),
+        None => format!("at {}", at.format("%Y-%m-%d %H:%M UTC")),
+    }
+}
+
+fn format_part(
+    start: Option<DateTime<Utc>>,
+    day_entries: &[&JournalEntry],
+    part: u8,
+) -> Option<String> {
+    let mut verdicts = vec![];
+    let mut star = None;
+    for entry in day_entries.iter() {
+        match entry.event {
+            JournalEvent::Submission { part: p, verdict } if p == part => verdicts.push(verdict),
+            JournalEvent::StarEarned { part: p } if p == part && star.is_none() => {
+                star = Some(entry.at)
+            }
+            _ => {}
+        }
+    }
+    if verdicts.is_empty() && star.is_none() {
+        return None;
+    }
+    let mut line = format!("- Part {}: ", part);
+    match star {
+        Some(at) => line.push_str(&format!("star {}", format_moment(start, at))),
+        None => line.push_str("no star yet"),
+    }
+    let nb_submissions = verdicts.len();
+    if nb_submissions > 0 {
+        let plural = if nb_submissions > 1 { "s" } else { "" };
+        line.push_str(&format!(", {} submission{}", nb_submissions, plural));
+        let wrongs: Vec<&str> = verdicts
+            .iter()
+            .filter(|v| **v != Verdict::Correct)
+            .map(|v| match v {
+                Verdict::TooHigh => "too high",
+                Verdict::TooLow => "too low",
+                Verdict::RateLimited => "too soon",
+                Verdict::WrongLevel => "wrong level",
+                _ => "wrong",
+            })
+            .collect();
+        if !wrongs.is_empty() {
+            line.push_str(&format!(" ({})", wrongs.join(", ")));
+        }
+    }
+    Some(line)
+}
+
+pub(crate) fn stats_block(day: u8, entries: &[JournalEntry]) -> String {
+    let day_entries: Vec<&JournalEntry> = entries.iter().filter(|e| e.day == day).collect();
+    let start = day_entries
+        .iter()
+        .find(|e| e.event == JournalEvent::InputFetched)
+        .map(|e| e.at);
+
+    let mut lines = vec![block_start(day)];
+    if let Some(s) = start {
+        lines.push(format!(
+            "- Input fetched on {}",
+            s.format("%Y-%m-%d at %H:%M UTC")
+        ));
+    }
+    if let Some(green) = day_entries
+        .iter()
+        .find(|e| e.event == JournalEvent::FirstTestGreen)
+    {
+        lines.push(format!(
+            "- First test green {}",
+            format_moment(start, green.at)
+        ));
+    }
+    for part in 1..=2 {
+        if let Some(line) = format_part(start, &day_entries, part) {
+            lines.push(line);
+        }
+    }
+    lines.push(BLOCK_END.to_string());
+    lines.join("\n")
+}
+
+// replace (or add) the block at the end of the day section,
+// everything else in the readme is left as is
+fn splice_block(readme: &str, day: u8, block: &str) -> String {
+    let mut lines: Vec<String> = readme.lines().map(|l| l.to_string()).collect();
+    let heading = format!("## Day {}", day);
+    let start_marker = block_start(day);
+
+    if let Some(start) = lines.iter().position(|l| l.trim() == start_marker) {
+        let end = lines[start..]
+            .iter()
+            .position(|l| l.trim() == BLOCK_END)
+            .map(|i| start + i)
+            .unwrap_or(start);
+        lines.splice(start..=end, block.lines().map(|l| l.to_string()));
+    } else if let Some(heading_idx) = lines.iter().position(|l| l.trim() == heading) {
+        let mut section_end = lines[heading_idx + 1..]
+            .iter()
+            .position(|l| l.starts_with("## "))
+            .map(|i| heading_idx + 1 + i)
+            .unwrap_or(lines.len());
+        // keep the blank lines between sections where they are
+        while section_end > heading_idx + 1 && lines[section_end - 1].trim().is_empty() {
+            section_end -= 1;
+        }
+        let mut inserted = vec![String::new()];
+        inserted.extend(block.lines().map(|l| l.to_string()));
+        lines.splice(section_end..section_end, inserted);
+    } else {
+        lines.push(String::new());
+        lines.push(heading);
+        lines.push(String::new());
+        lines.extend(block.lines().map(|l| l.to_string()));
+    }
+    let mut result = lines.join("\n");
+    if readme.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+pub(crate) fn refresh_readme(readme: &str, entries: &[JournalEntry]) -> String {
+    let days: BTreeSet<u8> = entries.iter().map(|e| e.day).collect();
+    let mut result = readme.to_string();
+    for day in days {
+        result = splice_block(&result, day, &stats_block(day, entries));
+    }
+    result
+}
+
+#[cfg(test)]
+mod readme_test {
+    use super::*;
+    use chrono::TimeZone;
+
+    const README: &str = "# advent-of-code-2022
+
+## Day 13
+
+Using Python is much easier.
+
+## Day 14
+
+Got the right answer on first try.
+I had a one-off error and had to retry once.
+";
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, 12, 14, hour, minute, 0).unwrap()
+    }
+
+    fn day_14_entries() -> Vec<JournalEntry> {
+        vec![
+            JournalEntry {
+                at: at(5, 0),
+                day: 14,
+                event: JournalEvent::InputFetched,
+            },
+            JournalEntry {
+                at: at(5, 22),
+                day: 14,
+                event: JournalEvent::FirstTestGreen,
+            },
+            JournalEntry {
+                at: at(5, 31),
+                day: 14,
+                event: JournalEvent::Submission {
+                    part: 1,
+                    verdict: Verdict::Correct,
+                },
+            },
+            JournalEntry {
+                at: at(5, 31),
+                day: 14,
+                event: JournalEvent::StarEarned { part: 1 },
+            },
+            JournalEntry {
+                at: at(6, 2),
+                day: 14,
+                event: JournalEvent::Submission {
+                    part: 2,
+                    verdict: Verdict::TooLow,
+                },
+            },
+            JournalEntry {
+                at: at(6, 5),
+                day: 14,
+                event: JournalEvent::Submission {
+                    part: 2,
+                    verdict: Verdict::Correct,
+                },
+            },
+            JournalEntry {
+                at: at(6, 5),
+                day: 14,
+                event: JournalEvent::StarEarned { part: 2 },
+            },
+        ]
+    }
+
+    #[test]
+    fn it_can_format_elapsed() {
+        assert_eq!(format_elapsed(at(5, 0), at(5, 20)), "20mn");
+        assert_eq!(format_elapsed(at(5, 0), at(6, 5)), "1h05mn");
+    }
+
+    #[test]
+    fn it_can_build_stats_block() {
+        let block = stats_block(14, &day_14_entries());
+        let expected = "<!-- journal day 14 -->
+- Input fetched on 2022-12-14 at 05:00 UTC
+- First test green after 22mn
+- Part 1: star after 31mn, 1 submission
+- Part 2: star after 1h05mn, 2 submissions (too low)
+<!-- /journal -->";
+        assert_eq!(block, expected);
+    }
+
+    #[test]
+    fn it_uses_time_of_day_without_fetch() {
+        let entries = vec![JournalEntry {
+            at: at(7, 10),
+            day: 14,
+            event: JournalEvent::Submission {
+                part: 1,
+                verdict: Verdict::TooHigh,
+            },
+        }];
+        let block = stats_block(14, &entries);
+        assert!(block.contains("- Part 1: no star yet, 1 submission (too high)"));
+        assert!(!block.contains("Input fetched"));
+    }
+
+    #[test]
+    fn it_adds_block_without_touching_prose() {
+        let refreshed = refresh_readme(README, &day_14_entries());
+        let expected = "# advent-of-code-2022
+
+## Day 13
+
+Using Python is much easier.
+
+## Day 14
+
+Got the right answer on first try.
+I had a one-off error and had to retry once.
+
+<!-- journal day 14 -->
+- Input fetched on 2022-12-14 at 05:00 UTC
+- First test green after 22mn
+- Part 1: star after 31mn, 1 submission
+- Part 2: star after 1h05mn, 2 submissions (too low)
+<!-- /journal -->
+";
+        assert_eq!(refreshed, expected);
+    }
+
+    #[test]
+    fn it_refreshes_existing_block() {
+        let mut entries = day_14_entries();
+        let first = refresh_readme(README, &entries[..2]);
+        assert!(first.contains("First test green"));
+        assert!(!first.contains("Part 1"));
+
+        entries.truncate(4);
+        let second = refresh_readme(&first, &entries);
+        assert_eq!(second.matches("<!-- journal day 14 -->").count(), 1);
+        assert!(second.contains("- Part 1: star after 31mn, 1 submission"));
+        // refreshing twice changes nothing
+        assert_eq!(refresh_readme(&second, &entries), second);
+    }
+
+    #[test]
+    fn it_inserts_block_before_next_section() {
+        let entries = vec![JournalEntry {
+            at: at(5, 0),
+            day: 13,
+            event: JournalEvent::InputFetched,
+        }];
+        let refreshed = refresh_readme(README, &entries);
+        assert!(refreshed.contains(
+            "Using Python is much easier.
+
+<!-- journal day 13 -->
+- Input fetched on 2022-12-14 at 05:00 UTC
+<!-- /journal -->
+
+## Day 14"
+        ));
+    }
+
+    #[test]
+    fn it_adds_missing_day_section() {
+        let entries = vec![JournalEntry {
+            at: at(5, 0),
+            day: 15,
+            event: JournalEvent::InputFetched,
+        }];
+        let refreshed = refresh_readme(README, &entries);
+        assert!(refreshed.starts_with(README.trim_end()));
+        assert!(refreshed.ends_with(
+            "## Day 15
+
+<!-- journal day 15 -->
+- Input fetched on 2022-12-14 at 05:00 UTC
+<!-- /journal -->
+"
+        ));
+    }
+}
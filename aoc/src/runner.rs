@@ -0,0 +1,403 @@
+use crate::answers::{read_answers, Answers};
+use crate::consistency::{python_implementation, CommandImplementation, Implementation};
+use crate::scaffold::cached_input_path;
+use crate::solvers::{rust_solvers, Solver};
+use rayon::prelude::*;
+use std::any::Any;
+use std::cell::Cell;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+pub(crate) enum Solvers {
+    // the functions of a day crate, linked in
+    Rust([Solver; 2]),
+    // a program given the input on stdin, printing its answers as its `format` says
+    External(CommandImplementation),
+}
+
+pub(crate) struct RegisteredDay {
+    pub(crate) day: u8,
+    pub(crate) solvers: Solvers,
+}
+
+// the rust days, then day 13 through its python package when `root` has it,
+// an error if ADVENT_PYTHON is blank
+pub(crate) fn registered_days(root: &Path) -> Result<Vec<RegisteredDay>, String> {
+    let mut days: Vec<RegisteredDay> = rust_solvers()
+        .into_iter()
+        .map(|(day, parts)| RegisteredDay {
+            day,
+            solvers: Solvers::Rust(parts),
+        })
+        .collect();
+    if let Some(python) = python_implementation(root, 13)? {
+        days.push(RegisteredDay {
+            day: 13,
+            solvers: Solvers::External(python),
+        });
+    }
+    days.sort_by_key(|d| d.day);
+    Ok(days)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum PartResult {
+    Answer(String),
+    Panicked(String),
+    // an external solver that crashed or printed no answer for the part
+    Failed(String),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Verification {
+    Correct,
+    Wrong,
+    // nothing in answers.tsv yet
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct PartReport {
+    pub(crate) result: PartResult,
+    pub(crate) verification: Verification,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct DayReport {
+    pub(crate) day: u8,
+    // Err when there is no cached input
+    pub(crate) parts: Result<Vec<PartReport>, String>,
+    pub(crate) elapsed: Duration,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+thread_local! {
+    // set while this thread runs a solver, its panic is reported as a PartResult
+    static IN_SOLVER: Cell<bool> = const { Cell::new(false) };
+}
+
+// installed once and kept, other panics still reach the previous hook
+fn quiet_solver_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !IN_SOLVER.with(|flag| flag.get()) {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+fn run_part(solver: Solver, input: String) -> PartResult {
+    quiet_solver_panics();
+    IN_SOLVER.with(|flag| flag.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solver(input)));
+    IN_SOLVER.with(|flag| flag.set(false));
+    match result {
+        Ok(answer) => PartResult::Answer(answer),
+        Err(payload) => PartResult::Panicked(panic_message(payload)),
+    }
+}
+
+fn verify(result: &PartResult, expected: Option<&String>) -> Verification {
+    match (result, expected) {
+        (_, None) => Verification::Unknown,
+        (PartResult::Answer(answer), Some(expected)) if answer == expected => Verification::Correct,
+        _ => Verification::Wrong,
+    }
+}
+
+// the result of each part, an external solver runs once for both
+fn solve_parts(solvers: &Solvers, input: String) -> Vec<PartResult> {
+    match solvers {
+        Solvers::Rust(parts) => parts
+            .iter()
+            .map(|solver| run_part(*solver, input.clone()))
+            .collect(),
+        Solvers::External(command) => match command.solve(&input) {
+            Ok(answers) => (0..2)
+                .map(|i| match answers.get(i) {
+                    Some(answer) => PartResult::Answer(answer.clone()),
+                    None => PartResult::Failed(format!("no answer for part {}", i + 1)),
+                })
+                .collect(),
+            Err(message) => vec![PartResult::Failed(message.clone()), PartResult::Failed(message)],
+        },
+    }
+}
+
+pub(crate) fn run_day(day: &RegisteredDay, input: Option<String>, answers: &Answers) -> DayReport {
+    let start = Instant::now();
+    let parts = match input {
+        None => Err("no cached input".to_string()),
+        Some(input) => Ok(solve_parts(&day.solvers, input)
+            .into_iter()
+            .enumerate()
+            .map(|(i, result)| {
+                let verification = verify(&result, answers.get(&(day.day, i as u8 + 1)));
+                PartReport {
+                    result,
+                    verification,
+                }
+            })
+            .collect()),
+    };
+    DayReport {
+        day: day.day,
+        parts,
+        elapsed: start.elapsed(),
+    }
+}
+
+// every day on the rayon pool, a panic only fails its own part
+pub(crate) fn run_days(days: &[RegisteredDay], root: &Path) -> io::Result<Vec<DayReport>> {
+    let answers = read_answers(root)?;
+    Ok(days
+        .par_iter()
+        .map(|day| {
+            let input = fs::read_to_string(cached_input_path(root, day.day)).ok();
+            run_day(day, input, &answers)
+        })
+        .collect())
+}
+
+impl DayReport {
+    pub(crate) fn status(&self) -> &'static str {
+        match &self.parts {
+            Err(_) => "failed",
+            Ok(parts) => {
+                if parts
+                    .iter()
+                    .any(|p| matches!(p.result, PartResult::Panicked(_) | PartResult::Failed(_)))
+                {
+                    "failed"
+                } else if parts.iter().any(|p| p.verification == Verification::Wrong) {
+                    "wrong"
+                } else if parts
+                    .iter()
+                    .all(|p| p.verification == Verification::Correct)
+                {
+                    "ok"
+                } else {
+                    "unverified"
+                }
+            }
+        }
+    }
+
+    pub(crate) fn is_failure(&self) -> bool {
+        matches!(self.status(), "failed" | "wrong")
+    }
+}
+
+// one line cells, the CRT answer of day 10 spans several lines
+fn cell(text: &str, width: usize) -> String {
+    let flat = text.trim_end().replace('\n', "|");
+    if flat.chars().count() > width {
+        let cut: String = flat.chars().take(width - 1).collect();
+        format!("{}…", cut)
+    } else {
+        flat
+    }
+}
+
+fn part_cell(part: &PartReport) -> String {
+    let text = match &part.result {
+        PartResult::Answer(answer) => answer.clone(),
+        PartResult::Panicked(message) => format!("panic: {}", message),
+        PartResult::Failed(message) => format!("error: {}", message),
+    };
+    let mark = match part.verification {
+        Verification::Correct => " ✓",
+        Verification::Wrong => " ✗",
+        Verification::Unknown => "",
+    };
+    format!("{}{}", cell(&text, 22), mark)
+}
+
+pub(crate) fn format_table(reports: &[DayReport]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<24}  {:<24}  {:<10}  {:>9}\n",
+        "day", "part 1", "part 2", "status", "time"
+    );
+    for report in reports {
+        let (one, two) = match &report.parts {
+            Ok(parts) => (part_cell(&parts[0]), part_cell(&parts[1])),
+            Err(message) => (cell(message, 24), String::new()),
+        };
+        table.push_str(&format!(
+            "{:>3}  {:<24}  {:<24}  {:<10}  {:>7.1}ms\n",
+            report.day,
+            one,
+            two,
+            report.status(),
+            report.elapsed.as_secs_f64() * 1000.0
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod runner_test {
+    use super::*;
+    use crate::consistency::AnswerFormat;
+
+    fn fake_day() -> RegisteredDay {
+        RegisteredDay {
+            day: 3,
+            solvers: Solvers::Rust([|input| input.len().to_string(), |_| panic!("boom")]),
+        }
+    }
+
+    #[test]
+    fn it_isolates_panics() {
+        let report = run_day(&fake_day(), Some("abcd".to_string()), &Answers::new());
+        let parts = report.parts.clone().unwrap();
+        assert_eq!(parts[0].result, PartResult::Answer("4".to_string()));
+        assert_eq!(parts[1].result, PartResult::Panicked("boom".to_string()));
+        assert_eq!(report.status(), "failed");
+        assert!(report.is_failure());
+    }
+
+    #[test]
+    fn it_verifies_stored_answers() {
+        let day = RegisteredDay {
+            day: 3,
+            solvers: Solvers::Rust([
+                |input| input.len().to_string(),
+                |input| input.to_uppercase(),
+            ]),
+        };
+        let mut answers = Answers::new();
+        answers.insert((3, 1), "4".to_string());
+        let report = run_day(&day, Some("abcd".to_string()), &answers);
+        assert_eq!(report.status(), "unverified");
+        assert!(!report.is_failure());
+
+        answers.insert((3, 2), "ABCD".to_string());
+        let report = run_day(&day, Some("abcd".to_string()), &answers);
+        assert_eq!(report.status(), "ok");
+
+        answers.insert((3, 2), "EFGH".to_string());
+        let report = run_day(&day, Some("abcd".to_string()), &answers);
+        assert_eq!(report.parts.unwrap()[1].verification, Verification::Wrong);
+    }
+
+    #[test]
+    fn it_fails_without_input() {
+        let report = run_day(&fake_day(), None, &Answers::new());
+        assert!(report.parts.is_err());
+        assert!(report.is_failure());
+    }
+
+    #[test]
+    fn it_runs_registered_days() {
+        let root = tempfile::tempdir().unwrap();
+        let example = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+        crate::scaffold::write_cache(&cached_input_path(root.path(), 1), example).unwrap();
+        crate::answers::store_answer(root.path(), 1, 1, "24000").unwrap();
+        crate::answers::store_answer(root.path(), 1, 2, "45000").unwrap();
+
+        let days: Vec<RegisteredDay> = registered_days(root.path())
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.day <= 2)
+            .collect();
+        let reports = run_days(&days, root.path()).unwrap();
+        assert_eq!(reports[0].status(), "ok");
+        // no cached input for day 2
+        assert_eq!(reports[1].status(), "failed");
+
+        let table = format_table(&reports);
+        assert!(table.contains("24000 ✓"));
+        assert!(table.lines().nth(1).unwrap().trim_start().starts_with("1 "));
+    }
+
+    #[test]
+    fn it_keeps_drawings_that_are_not_letters() {
+        let root = tempfile::tempdir().unwrap();
+        let day_10 = registered_days(root.path()).unwrap().remove(9);
+        let Solvers::Rust([_, solve_two]) = day_10.solvers else {
+            panic!("day 10 is linked in");
+        };
+        let example = include_str!("../../day-10/examples/example.txt");
+        let drawing = include_str!("../../day-10/examples/example.part2");
+        assert_eq!(solve_two(example.to_string()), drawing.trim_end());
+    }
+
+    #[test]
+    fn it_reports_unreadable_answers() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(crate::answers::answers_path(root.path())).unwrap();
+        assert!(run_days(&registered_days(root.path()).unwrap(), root.path()).is_err());
+    }
+
+    #[test]
+    fn it_only_quiets_panics_of_solvers() {
+        let result = run_part(|_| panic!("boom"), String::new());
+        assert_eq!(result, PartResult::Panicked("boom".to_string()));
+        assert!(!IN_SOLVER.with(|flag| flag.get()));
+    }
+
+    fn external_day(script: &str) -> RegisteredDay {
+        RegisteredDay {
+            day: 13,
+            solvers: Solvers::External(CommandImplementation {
+                name: "python".to_string(),
+                program: "sh".to_string(),
+                args: vec!["-c".to_string(), script.to_string()],
+                dir: std::env::temp_dir(),
+                format: AnswerFormat::Numbered,
+            }),
+        }
+    }
+
+    #[test]
+    fn it_runs_external_days() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(registered_days(root.path()).unwrap().iter().all(|d| d.day != 13));
+        std::fs::create_dir_all(root.path().join("day-13-py")).unwrap();
+        std::fs::write(root.path().join("day-13-py/pyproject.toml"), "").unwrap();
+        let days = registered_days(root.path()).unwrap();
+        assert_eq!(days.iter().map(|d| d.day).nth(11), Some(13));
+        assert_eq!(days.last().unwrap().day, 14);
+
+        // counts the pairs of the input, like a solver would
+        let day = external_day("echo 1 $(grep -c . -); echo 2 140");
+        let mut answers = Answers::new();
+        answers.insert((13, 1), "4".to_string());
+        answers.insert((13, 2), "140".to_string());
+        let report = run_day(&day, Some("[1]\n[2]\n\n[3]\n[4]\n".to_string()), &answers);
+        assert_eq!(report.status(), "ok");
+        assert!(format_table(&[report]).contains("140 ✓"));
+
+        let report = run_day(&external_day("echo 1 13"), Some(String::new()), &Answers::new());
+        let parts = report.parts.clone().unwrap();
+        assert_eq!(parts[0].result, PartResult::Answer("13".to_string()));
+        assert_eq!(parts[1].result, PartResult::Failed("no answer for part 2".to_string()));
+        assert_eq!(report.status(), "failed");
+
+        let report = run_day(&external_day("exit 3"), Some(String::new()), &Answers::new());
+        assert!(format_table(&[report]).contains("error: exit status: 3"));
+    }
+
+    #[test]
+    fn it_can_shorten_cells() {
+        assert_eq!(cell("##..\n..##\n", 22), "##..|..##");
+        assert_eq!(cell("abcdef", 4), "abc…");
+    }
+}
@@ -0,0 +1,168 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub(crate) fn day_dirname(day: u8) -> String {
+    format!("day-{:02}", day)
+}
+
+pub(crate) fn cache_dir(root: &Path) -> PathBuf {
+    root.join(".cache")
+}
+
+pub(crate) fn cached_input_path(root: &Path, day: u8) -> PathBuf {
+    cache_dir(root).join(format!("{}.txt", day_dirname(day)))
+}
+
+#[cfg(any(feature = "network", test))]
+pub(crate) fn cached_statement_path(root: &Path, day: u8) -> PathBuf {
+    cache_dir(root).join(format!("{}.html", day_dirname(day)))
+}
+
+pub(crate) fn write_cache(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)
+}
+
+// the blanks of the template: the day number, the crate folder
+// (package name, embedded input path) and the library name
+#[cfg(any(feature = "network", test))]
+fn fill_template(text: &str, day: u8) -> String {
+    let dirname = day_dirname(day);
+    text.replace("const DAY: u8 = ;", &format!("const DAY: u8 = {};", day))
+        .replace("day-XX", &dirname)
+        .replace("day_XX", &dirname.replace('-', "_"))
+}
+
+// the template files in `folder`, filled, into the same folder of the day
+#[cfg(any(feature = "network", test))]
+fn copy_template_folder(
+    template: &Path,
+    destination: &Path,
+    folder: &str,
+    day: u8,
+) -> io::Result<()> {
+    let source_dir = template.join(folder);
+    if !source_dir.exists() {
+        return Ok(());
+    }
+    fs::create_dir_all(destination.join(folder))?;
+    for entry in fs::read_dir(source_dir)? {
+        let entry = entry?;
+        let source = fs::read_to_string(entry.path())?;
+        fs::write(
+            destination.join(folder).join(entry.file_name()),
+            fill_template(&source, day),
+        )?;
+    }
+    Ok(())
+}
+
+// copy the template folder into day-NN, filling the blanks,
+// returns None if the crate already exists
+#[cfg(any(feature = "network", test))]
+pub(crate) fn scaffold_day(root: &Path, day: u8) -> io::Result<Option<PathBuf>> {
+    let template = root.join("template");
+    let destination = root.join(day_dirname(day));
+    if destination.exists() {
+        return Ok(None);
+    }
+    fs::create_dir_all(&destination)?;
+
+    let cargo_toml = fs::read_to_string(template.join("Cargo.toml"))?;
+    fs::write(
+        destination.join("Cargo.toml"),
+        fill_template(&cargo_toml, day),
+    )?;
+    if let Ok(build_rs) = fs::read_to_string(template.join("build.rs")) {
+        fs::write(destination.join("build.rs"), build_rs)?;
+    }
+    for folder in ["src", "tests", "examples"] {
+        copy_template_folder(&template, &destination, folder, day)?;
+    }
+
+    Ok(Some(destination))
+}
+
+#[cfg(test)]
+mod scaffold_test {
+    use super::*;
+
+    fn fake_root() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let template = root.path().join("template");
+        fs::create_dir_all(template.join("src")).unwrap();
+        fs::write(
+            template.join("Cargo.toml"),
+            "[package]\nname = \"day-XX\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        fs::write(
+            template.join("src").join("lib.rs"),
+            "pub const DAY: u8 = ;\n",
+        )
+        .unwrap();
+        fs::write(
+            template.join("src").join("main.rs"),
+            "fn main() {\n    day_XX::run();\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            template.join("src").join("input_source.rs"),
+            "include_str!(\"../../.cache/day-XX.txt\")\n",
+        )
+        .unwrap();
+        fs::write(template.join("build.rs"), "fn main() {}\n").unwrap();
+        fs::create_dir_all(template.join("examples")).unwrap();
+        fs::write(template.join("examples").join("example.txt"), "").unwrap();
+        root
+    }
+
+    #[test]
+    fn it_can_name_paths() {
+        let root = Path::new("/repo");
+        assert_eq!(day_dirname(3), "day-03");
+        assert_eq!(
+            cached_input_path(root, 15),
+            PathBuf::from("/repo/.cache/day-15.txt")
+        );
+        assert_eq!(
+            cached_statement_path(root, 15),
+            PathBuf::from("/repo/.cache/day-15.html")
+        );
+    }
+
+    #[test]
+    fn it_can_scaffold_from_template() {
+        let root = fake_root();
+        let created = scaffold_day(root.path(), 15).unwrap();
+        assert_eq!(created, Some(root.path().join("day-15")));
+
+        let cargo_toml = fs::read_to_string(root.path().join("day-15/Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains("name = \"day-15\""));
+        let lib_rs = fs::read_to_string(root.path().join("day-15/src/lib.rs")).unwrap();
+        assert!(lib_rs.starts_with("pub const DAY: u8 = 15;"));
+        let main_rs = fs::read_to_string(root.path().join("day-15/src/main.rs")).unwrap();
+        assert!(main_rs.contains("day_15::run()"));
+        let input_source =
+            fs::read_to_string(root.path().join("day-15/src/input_source.rs")).unwrap();
+        assert!(input_source.contains("../../.cache/day-15.txt"));
+        assert!(root.path().join("day-15/build.rs").exists());
+        assert!(root.path().join("day-15/examples/example.txt").exists());
+        assert!(!root.path().join("day-15/tests").exists());
+    }
+
+    #[test]
+    fn it_does_not_overwrite_existing_day() {
+        let root = fake_root();
+        fs::create_dir_all(root.path().join("day-15/src")).unwrap();
+        fs::write(root.path().join("day-15/src/main.rs"), "// my work").unwrap();
+
+        let created = scaffold_day(root.path(), 15).unwrap();
+        assert_eq!(created, None);
+        let main_rs = fs::read_to_string(root.path().join("day-15/src/main.rs")).unwrap();
+        assert_eq!(main_rs, "// my work");
+    }
+}
@@ -0,0 +1,482 @@
+use advent_viewer::{Rect, Simulation};
+use std::rc::Rc;
+
+/// Crates moved one instruction at a time, by the 9001 model for the second part
+#[derive(Clone)]
+pub(crate) struct CraneSimulation {
+    warehouse: day_05::WarehouseState<char>,
+    instructions: Rc<[day_05::CraneInstruction]>,
+    next: usize,
+    model_9001: bool,
+}
+
+impl CraneSimulation {
+    pub(crate) fn new(input: String, part: u8) -> Self {
+        let parsed = day_05::parse_input(input);
+        CraneSimulation {
+            warehouse: parsed.initial().clone(),
+            instructions: parsed.instructions().into(),
+            next: 0,
+            model_9001: part == 2,
+        }
+    }
+}
+
+impl Simulation for CraneSimulation {
+    fn title(&self) -> String {
+        let model = if self.model_9001 { "9001" } else { "9000" };
+        format!("Day 5: CrateMover {}", model)
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(instruction) = self.instructions.get(self.next).cloned() else {
+            return false;
+        };
+        if self.model_9001 {
+            self.warehouse.execute_command_9001(instruction);
+        } else {
+            self.warehouse.execute_command(instruction);
+        }
+        self.next += 1;
+        true
+    }
+
+    fn status(&self) -> String {
+        match self.next.checked_sub(1).map(|i| &self.instructions[i]) {
+            Some(last) => format!(
+                "move {} of {}: {} from {} to {}",
+                self.next,
+                self.instructions.len(),
+                last.quantity,
+                last.from,
+                last.to
+            ),
+            None => format!("{} moves", self.instructions.len()),
+        }
+    }
+
+    // the stacks as drawn in the puzzle, the bottom ones when they don't fit
+    fn panel(&self, area: Rect) -> Vec<String> {
+        let piles = self.warehouse.piles();
+        let height = piles.iter().map(|p| p.len()).max().unwrap_or(0);
+        let mut rows: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                piles
+                    .iter()
+                    .map(|p| p.get(level).map_or("   ".to_string(), |c| format!("[{}]", c)))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect();
+        rows.push(
+            (1..=piles.len())
+                .map(|i| format!(" {} ", i))
+                .collect::<Vec<String>>()
+                .join(" "),
+        );
+        let skipped = rows.len().saturating_sub(area.height as usize);
+        rows.split_off(skipped)
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// The rope moved one square at a time, 2 knots or 10 for the second part
+#[derive(Clone)]
+pub(crate) struct RopeSimulation {
+    rope: day_09::Rope,
+    moves: Rc<[day_09::Movement]>,
+    next: usize,
+}
+
+impl RopeSimulation {
+    pub(crate) fn new(input: String, part: u8) -> Self {
+        let knots = if part == 2 { 10 } else { 2 };
+        let moves: Vec<day_09::Movement> = day_09::parse_input(input)
+            .iter()
+            .flat_map(|m| m.split_unit())
+            .collect();
+        RopeSimulation {
+            rope: day_09::Rope::new(day_09::Point::new(0, 0), knots),
+            moves: moves.into(),
+            next: 0,
+        }
+    }
+}
+
+impl Simulation for RopeSimulation {
+    fn title(&self) -> String {
+        format!("Day 9: rope of {} knots", self.rope.get_knots().len())
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(unit) = self.moves.get(self.next).cloned() else {
+            return false;
+        };
+        self.rope.apply_movement(unit);
+        self.next += 1;
+        true
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "step {} of {}, tail visited {}",
+            self.next,
+            self.moves.len(),
+            self.rope.get_tail_memory().len()
+        )
+    }
+
+    // centered on the head, H then the knots by number, # where the tail went
+    fn panel(&self, area: Rect) -> Vec<String> {
+        let knots = self.rope.get_knots();
+        let visited = self.rope.get_tail_memory();
+        let (width, height) = (area.width as isize, area.height as isize);
+        let (left, top) = (knots[0].x - width / 2, knots[0].y + height / 2);
+        (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|col| {
+                        let point = day_09::Point::new(left + col, top - row);
+                        match knots.iter().position(|k| *k == point) {
+                            Some(0) => 'H',
+                            Some(_) if knots.len() == 2 => 'T',
+                            Some(i) => char::from_digit(i as u32, 10).unwrap(),
+                            None if point == day_09::Point::new(0, 0) => 's',
+                            None if visited.contains(&point) => '#',
+                            None => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+const CRT_WIDTH: usize = 40;
+const CRT_PIXELS: usize = 240;
+
+/// The CPU one cycle at a time, drawing a pixel of the CRT each cycle
+#[derive(Clone)]
+pub(crate) struct CpuSimulation {
+    // X during each cycle, from the first one
+    registers: Rc<[isize]>,
+    drawn: usize,
+}
+
+impl CpuSimulation {
+    pub(crate) fn new(input: String) -> Self {
+        CpuSimulation {
+            registers: day_10::apply_commands(day_10::parse_input(input)).into(),
+            drawn: 0,
+        }
+    }
+
+    fn is_lit(&self, pixel: usize) -> bool {
+        let column = (pixel % CRT_WIDTH) as isize;
+        (self.registers[pixel] - column).abs() <= 1
+    }
+}
+
+impl Simulation for CpuSimulation {
+    fn title(&self) -> String {
+        "Day 10: CPU and CRT".to_string()
+    }
+
+    fn step(&mut self) -> bool {
+        if self.drawn >= CRT_PIXELS.min(self.registers.len() - 1) {
+            return false;
+        }
+        self.drawn += 1;
+        true
+    }
+
+    fn status(&self) -> String {
+        let cycle = self.drawn + 1;
+        match self.registers.get(self.drawn) {
+            Some(x) => format!("cycle {}, X = {}, signal strength {}", cycle, x, cycle as isize * x),
+            None => format!("cycle {}", cycle),
+        }
+    }
+
+    // the sprite over the current row, then the screen drawn so far
+    fn panel(&self, _area: Rect) -> Vec<String> {
+        let x = self.registers.get(self.drawn).copied().unwrap_or(0);
+        let sprite: String = (0..CRT_WIDTH as isize)
+            .map(|column| if (x - column).abs() <= 1 { '#' } else { ' ' })
+            .collect();
+        let mut rows = vec![format!("sprite {}", sprite)];
+        for row in 0..CRT_PIXELS / CRT_WIDTH {
+            let pixels: String = (row * CRT_WIDTH..(row + 1) * CRT_WIDTH)
+                .map(|pixel| match pixel {
+                    p if p >= self.drawn => ' ',
+                    p if self.is_lit(p) => '#',
+                    _ => '.',
+                })
+                .collect();
+            rows.push(format!("       {}", pixels));
+        }
+        rows
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// Monkeys playing round after round, 20 of them or 10000 without relief
+#[derive(Clone)]
+pub(crate) struct MonkeySimulation {
+    arena: day_11::MonkeyArena,
+    rounds: usize,
+}
+
+impl MonkeySimulation {
+    pub(crate) fn new(input: String, part: u8) -> Self {
+        let mut arena = day_11::parse_input(input);
+        arena.set_no_worries(part == 2);
+        MonkeySimulation {
+            arena,
+            rounds: if part == 2 { 10_000 } else { 20 },
+        }
+    }
+}
+
+impl Simulation for MonkeySimulation {
+    fn title(&self) -> String {
+        "Day 11: monkeys in the middle".to_string()
+    }
+
+    fn step(&mut self) -> bool {
+        if self.arena.round() == self.rounds {
+            return false;
+        }
+        self.arena.play_round();
+        true
+    }
+
+    fn status(&self) -> String {
+        let mut businesses = self.arena.get_monkey_business();
+        businesses.sort_unstable_by(|a, b| b.cmp(a));
+        let business: usize = businesses.iter().take(2).product();
+        format!(
+            "round {} of {}, monkey business {}",
+            self.arena.round(),
+            self.rounds,
+            business
+        )
+    }
+
+    fn panel(&self, _area: Rect) -> Vec<String> {
+        self.arena
+            .monkeys
+            .iter()
+            .zip(self.arena.get_monkey_business())
+            .enumerate()
+            .map(|(i, (monkey, inspected))| {
+                let items: Vec<String> = monkey.items().iter().map(|w| w.to_string()).collect();
+                format!("Monkey {} inspected {:>6}: {}", i, inspected, items.join(", "))
+            })
+            .collect()
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// Sand falling one grain at a time, until the abyss or, with the floor, the source is blocked
+#[derive(Clone)]
+pub(crate) struct SandSimulation {
+    grid: day_14::FallingGrid,
+    rocks: Rc<day_14::FallingGrid>,
+    grains: usize,
+    floor: bool,
+}
+
+impl SandSimulation {
+    pub(crate) fn new(input: String, part: u8) -> Self {
+        let mut grid = day_14::prepare_grid(day_14::parse_input(input));
+        if part == 2 {
+            grid.set_floor();
+        }
+        SandSimulation {
+            rocks: Rc::new(grid.clone()),
+            grid,
+            grains: 0,
+            floor: part == 2,
+        }
+    }
+}
+
+impl Simulation for SandSimulation {
+    fn title(&self) -> String {
+        let ground = if self.floor { "with a floor" } else { "over the abyss" };
+        format!("Day 14: sand {}", ground)
+    }
+
+    fn step(&mut self) -> bool {
+        if self.grid.is_point_occupied(&day_14::STARTING_FALL) {
+            return false;
+        }
+        if self.grid.fall_one_sand(day_14::STARTING_FALL) == day_14::GrainStatus::Falling {
+            return false;
+        }
+        self.grains += 1;
+        true
+    }
+
+    fn status(&self) -> String {
+        format!("{} grains at rest", self.grains)
+    }
+
+    // from the top of the cave, centered on the source
+    fn panel(&self, area: Rect) -> Vec<String> {
+        let left = day_14::STARTING_FALL.x.saturating_sub(area.width as usize / 2);
+        (0..area.height as usize)
+            .map(|y| {
+                (left..left + area.width as usize)
+                    .map(|x| {
+                        let point = day_14::Point { x, y };
+                        if self.rocks.is_point_occupied(&point) {
+                            '#'
+                        } else if self.grid.is_point_occupied(&point) {
+                            'o'
+                        } else if point == day_14::STARTING_FALL {
+                            '+'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// The simulation of a day's part, None for the days without one
+pub(crate) fn simulation_for(day: u8, part: u8, input: String) -> Option<Box<dyn Simulation>> {
+    Some(match day {
+        5 => Box::new(CraneSimulation::new(input, part)),
+        9 => Box::new(RopeSimulation::new(input, part)),
+        10 => Box::new(CpuSimulation::new(input)),
+        11 => Box::new(MonkeySimulation::new(input, part)),
+        14 => Box::new(SandSimulation::new(input, part)),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod simulations_test {
+    use super::*;
+    use advent_viewer::{render, Player};
+
+    fn example(day: u8) -> String {
+        let path = format!("{}/../day-{:02}/examples/example.txt", env!("CARGO_MANIFEST_DIR"), day);
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    fn finish(simulation: &mut dyn Simulation) -> usize {
+        let mut steps = 0;
+        while simulation.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    fn area(width: u16, height: u16) -> Rect {
+        Rect::new(0, 0, width, height)
+    }
+
+    #[test]
+    fn it_moves_crates() {
+        let mut crane = CraneSimulation::new(example(5), 1);
+        assert_eq!(crane.status(), "4 moves");
+        assert_eq!(crane.panel(area(20, 10)), vec!["    [D]    ", "[N] [C]    ", "[Z] [M] [P]", " 1   2   3 "]);
+        assert!(crane.step());
+        assert_eq!(crane.status(), "move 1 of 4: 1 from 2 to 1");
+        assert_eq!(finish(&mut crane), 3);
+        assert_eq!(crane.warehouse.get_tops(), vec!['C', 'M', 'Z']);
+        // the tallest stack is cut from the top
+        assert_eq!(crane.panel(area(20, 2)), vec!["[C] [M] [P]", " 1   2   3 "]);
+
+        let mut crane = CraneSimulation::new(example(5), 2);
+        finish(&mut crane);
+        assert_eq!(crane.warehouse.get_tops(), vec!['M', 'C', 'D']);
+    }
+
+    #[test]
+    fn it_moves_the_rope() {
+        let mut rope = RopeSimulation::new(example(9), 1);
+        assert_eq!(rope.panel(area(3, 3)), vec!["...", ".H.", "..."]);
+        rope.step();
+        rope.step();
+        assert_eq!(rope.panel(area(5, 1)), vec!["sTH.."]);
+        assert_eq!(finish(&mut rope), 22);
+        assert_eq!(rope.status(), "step 24 of 24, tail visited 13");
+
+        let mut rope = RopeSimulation::new(example(9), 2);
+        finish(&mut rope);
+        assert_eq!(rope.rope.get_tail_memory().len(), 1);
+        assert!(rope.panel(area(9, 9)).iter().any(|row| row.contains('H')));
+    }
+
+    #[test]
+    fn it_draws_the_crt() {
+        let program = "noop\naddx 3\naddx -5\n".to_string();
+        let mut cpu = CpuSimulation::new(program);
+        assert_eq!(cpu.status(), "cycle 1, X = 1, signal strength 1");
+        assert_eq!(finish(&mut cpu), 5);
+        assert_eq!(cpu.status(), "cycle 6, X = -1, signal strength -6");
+        let panel = cpu.panel(area(50, 10));
+        assert_eq!(panel[0].trim_end(), "sprite #");
+        assert_eq!(panel[1].trim_end(), "       #####");
+        assert_eq!(panel.len(), 7);
+    }
+
+    #[test]
+    fn it_plays_monkey_rounds() {
+        let mut monkeys = MonkeySimulation::new(example(11), 1);
+        assert_eq!(monkeys.panel(area(60, 10))[0], "Monkey 0 inspected      0: 79, 98");
+        assert_eq!(finish(&mut monkeys), 20);
+        assert_eq!(monkeys.status(), "round 20 of 20, monkey business 10605");
+    }
+
+    #[test]
+    fn it_drops_sand() {
+        let mut sand = SandSimulation::new(example(14), 1);
+        assert_eq!(finish(&mut sand), 24);
+        let panel = sand.panel(area(10, 10));
+        assert_eq!(panel[0], ".....+....");
+        assert_eq!(panel[2], ".....o....");
+        assert_eq!(panel[9], "########..");
+
+        // until the source is blocked
+        let mut sand = SandSimulation::new(example(14), 2);
+        finish(&mut sand);
+        assert_eq!(sand.panel(area(10, 2)), vec![".....o....", "....ooo..."]);
+        assert!(!sand.step());
+    }
+
+    #[test]
+    fn it_draws_a_day_in_the_viewer() {
+        let mut player = Player::new(simulation_for(14, 1, example(14)).unwrap());
+        player.step();
+        let rows = render(&player, 20, 14);
+        assert_eq!(rows[0], "Day 14: sand over th");
+        assert!(rows[1].starts_with("┌1 grains at rest"));
+        assert_eq!(rows[11], "│...#########......│");
+        assert!(simulation_for(1, 1, String::new()).is_none());
+    }
+}
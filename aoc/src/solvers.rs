@@ -0,0 +1,44 @@
+// the Rust days, linked in: the `aoc` runner and the `advent-serve` binary both include this file
+
+// parse then solve one part, the answer as it would be submitted
+pub(crate) type Solver = fn(String) -> String;
+
+macro_rules! register {
+    ($day:expr, $krate:ident) => {
+        (
+            $day,
+            [
+                |input| $krate::solve_one($krate::parse_input(input)).to_string(),
+                |input| $krate::solve_two($krate::parse_input(input)).to_string(),
+            ],
+        )
+    };
+}
+
+/// The two solvers of each Rust day, by day
+pub(crate) fn rust_solvers() -> Vec<(u8, [Solver; 2])> {
+    vec![
+        register!(1, day_01),
+        register!(2, day_02),
+        register!(3, day_03),
+        register!(4, day_04),
+        register!(5, day_05),
+        register!(6, day_06),
+        register!(7, day_07),
+        register!(8, day_08),
+        register!(9, day_09),
+        // the CRT drawing is verified as the letters it shows, the way it is submitted
+        (
+            10,
+            [
+                |input| day_10::solve_one(day_10::parse_input(input)).to_string(),
+                |input| {
+                    let drawing = day_10::solve_two(day_10::parse_input(input));
+                    day_10::read_letters(&drawing).unwrap_or(drawing)
+                },
+            ],
+        ),
+        register!(11, day_11),
+        register!(14, day_14),
+    ]
+}
@@ -0,0 +1,186 @@
+use crate::answers::store_answer;
+use crate::client::{AdventSite, FetchError};
+use crate::clock::Clock;
+use crate::journal::{record, JournalEntry, JournalEvent, Verdict};
+use std::path::Path;
+
+pub(crate) fn parse_verdict(page: &str) -> Option<Verdict> {
+    if page.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("too low") {
+            Some(Verdict::TooLow)
+        } else {
+            Some(Verdict::Wrong)
+        }
+    } else if page.contains("You gave an answer too recently") {
+        Some(Verdict::RateLimited)
+    } else if page.contains("You don't seem to be solving the right level") {
+        Some(Verdict::WrongLevel)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn submit_and_record(
+    clock: &dyn Clock,
+    site: &dyn AdventSite,
+    root: &Path,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, FetchError> {
+    let page = site.answer(day, part, answer)?;
+    let verdict = parse_verdict(&page).ok_or(FetchError::UnexpectedPage)?;
+    let at = clock.now();
+    record(
+        root,
+        JournalEntry {
+            at,
+            day,
+            event: JournalEvent::Submission { part, verdict },
+        },
+    )
+    .unwrap();
+    if verdict == Verdict::Correct {
+        record(
+            root,
+            JournalEntry {
+                at,
+                day,
+                event: JournalEvent::StarEarned { part },
+            },
+        )
+        .unwrap();
+        // checked by `aoc run`
+        store_answer(root, day, part, answer).unwrap();
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod submit_test {
+    use super::*;
+    use crate::answers::read_answers;
+    use crate::client::HttpAdventSite;
+    use crate::clock::FakeClock;
+    use crate::journal::read_journal;
+    use advent_common::mock_server::MockAdventServer;
+    use chrono::{TimeZone, Utc};
+
+    struct ScriptedAnswers {
+        page: &'static str,
+    }
+
+    impl AdventSite for ScriptedAnswers {
+        fn input(&self, _day: u8) -> Result<String, FetchError> {
+            unreachable!()
+        }
+
+        fn statement(&self, _day: u8) -> Result<String, FetchError> {
+            unreachable!()
+        }
+
+        fn answer(&self, _day: u8, _part: u8, _answer: &str) -> Result<String, FetchError> {
+            Ok(self.page.to_string())
+        }
+    }
+
+    #[test]
+    fn it_can_parse_verdicts() {
+        assert_eq!(
+            parse_verdict("<p>That's the right answer! You are one gold star closer.</p>"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            parse_verdict("<p>That's not the right answer; your answer is too high.</p>"),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            parse_verdict("<p>That's not the right answer; your answer is too low.</p>"),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            parse_verdict("<p>That's not the right answer. If you're stuck...</p>"),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            parse_verdict("<p>You gave an answer too recently; you have to wait.</p>"),
+            Some(Verdict::RateLimited)
+        );
+        assert_eq!(
+            parse_verdict("<p>You don't seem to be solving the right level.</p>"),
+            Some(Verdict::WrongLevel)
+        );
+        assert_eq!(parse_verdict("<html>login</html>"), None);
+    }
+
+    #[test]
+    fn it_records_wrong_submission() {
+        let root = tempfile::tempdir().unwrap();
+        let clock = FakeClock::new(Utc.with_ymd_and_hms(2022, 12, 14, 6, 2, 0).unwrap());
+        let site = ScriptedAnswers {
+            page: "That's not the right answer; your answer is too low.",
+        };
+        let verdict = submit_and_record(&clock, &site, root.path(), 14, 2, "92").unwrap();
+        assert_eq!(verdict, Verdict::TooLow);
+        let journal = read_journal(root.path()).unwrap();
+        assert_eq!(
+            journal.iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+            vec![JournalEvent::Submission {
+                part: 2,
+                verdict: Verdict::TooLow
+            }]
+        );
+    }
+
+    #[test]
+    fn it_records_star_on_right_answer() {
+        let root = tempfile::tempdir().unwrap();
+        let clock = FakeClock::new(Utc.with_ymd_and_hms(2022, 12, 14, 6, 5, 0).unwrap());
+        let site = ScriptedAnswers {
+            page: "That's the right answer!",
+        };
+        submit_and_record(&clock, &site, root.path(), 14, 2, "93").unwrap();
+        let journal = read_journal(root.path()).unwrap();
+        assert_eq!(journal.len(), 2);
+        assert_eq!(journal[1].event, JournalEvent::StarEarned { part: 2 });
+        let answers = read_answers(root.path()).unwrap();
+        assert_eq!(answers.get(&(14, 2)), Some(&"93".to_string()));
+    }
+
+    #[test]
+    fn it_does_not_record_unknown_page() {
+        let root = tempfile::tempdir().unwrap();
+        let clock = FakeClock::new(Utc.with_ymd_and_hms(2022, 12, 14, 6, 5, 0).unwrap());
+        let site = ScriptedAnswers {
+            page: "<html></html>",
+        };
+        let result = submit_and_record(&clock, &site, root.path(), 14, 1, "24");
+        assert_eq!(result, Err(FetchError::UnexpectedPage));
+        assert_eq!(read_journal(root.path()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn it_submits_to_mock_server() {
+        let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        let server = MockAdventServer::new(&fixtures).serve("127.0.0.1:0");
+        let site = HttpAdventSite::new(&server.base_url, "session=abc");
+        let root = tempfile::tempdir().unwrap();
+        let clock = FakeClock::new(Utc.with_ymd_and_hms(2022, 12, 1, 5, 10, 0).unwrap());
+
+        let verdict = submit_and_record(&clock, &site, root.path(), 1, 1, "99999").unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+        let verdict = submit_and_record(&clock, &site, root.path(), 1, 1, "24000").unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+        let verdict = submit_and_record(&clock, &site, root.path(), 1, 2, "1").unwrap();
+        assert_eq!(verdict, Verdict::RateLimited);
+        // 3 submissions and a star
+        assert_eq!(read_journal(root.path()).unwrap().len(), 4);
+
+        let locked = submit_and_record(&clock, &site, root.path(), 25, 1, "1");
+        assert_eq!(locked, Err(FetchError::Status(404)));
+    }
+}
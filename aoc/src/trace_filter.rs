@@ -0,0 +1,132 @@
+use serde_json::Value;
+
+// Query of the JSON lines written by the days with `--trace`,
+// e.g. `--day 8 --event tree_visible --where row=3 --where direction=Left`
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct TraceFilter {
+    pub(crate) day: Option<u8>,
+    pub(crate) event: Option<String>,
+    pub(crate) fields: Vec<(String, String)>,
+}
+
+// "row=3" into ("row", "3"), for clap
+pub(crate) fn parse_field(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected FIELD=VALUE, got {:?}", arg)),
+    }
+}
+
+// strings are compared without their quotes, anything else as written in JSON
+fn field_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl TraceFilter {
+    fn field_matches(event: &Value, key: &str, expected: &str) -> bool {
+        event
+            .get(key)
+            .map(|v| field_text(v) == expected)
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn matches(&self, event: &Value) -> bool {
+        if let Some(day) = self.day {
+            if !Self::field_matches(event, "day", &day.to_string()) {
+                return false;
+            }
+        }
+        if let Some(name) = &self.event {
+            if !Self::field_matches(event, "event", name) {
+                return false;
+            }
+        }
+        self.fields
+            .iter()
+            .all(|(key, expected)| Self::field_matches(event, key, expected))
+    }
+
+    // lines that are not JSON (e.g. a truncated last line) are skipped
+    pub(crate) fn filter_lines<'a>(&self, content: &'a str) -> Vec<&'a str> {
+        content
+            .lines()
+            .filter(|line| match serde_json::from_str::<Value>(line) {
+                Ok(event) => self.matches(&event),
+                Err(_) => false,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod trace_filter_test {
+    use super::*;
+
+    const TRACE: &str = r#"{"col":0,"day":8,"direction":"Up","event":"tree_visible","row":0}
+{"col":2,"day":8,"direction":"Left","event":"tree_visible","row":3}
+{"day":14,"event":"grain_settled","x":500,"y":8}
+{"day":11,"event":"item_thrown","from":0,"round":1,"to":3,"worry":500.0}
+{"day":8,"direction":"Lef"#;
+
+    #[test]
+    fn it_can_parse_fields() {
+        assert_eq!(
+            parse_field("direction=Left"),
+            Ok(("direction".to_string(), "Left".to_string()))
+        );
+        assert_eq!(
+            parse_field("x=a=b"),
+            Ok(("x".to_string(), "a=b".to_string()))
+        );
+        assert!(parse_field("row").is_err());
+        assert!(parse_field("=3").is_err());
+    }
+
+    #[test]
+    fn it_can_filter_by_day_and_event() {
+        let filter = TraceFilter {
+            day: Some(8),
+            ..Default::default()
+        };
+        assert_eq!(filter.filter_lines(TRACE).len(), 2);
+
+        let filter = TraceFilter {
+            event: Some("grain_settled".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.filter_lines(TRACE),
+            vec![r#"{"day":14,"event":"grain_settled","x":500,"y":8}"#]
+        );
+        assert_eq!(TraceFilter::default().filter_lines(TRACE).len(), 4);
+    }
+
+    #[test]
+    fn it_can_filter_by_fields() {
+        let filter = TraceFilter {
+            day: Some(8),
+            event: Some("tree_visible".to_string()),
+            fields: vec![
+                ("row".to_string(), "3".to_string()),
+                ("direction".to_string(), "Left".to_string()),
+            ],
+        };
+        let lines = filter.filter_lines(TRACE);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("\"col\":2"));
+
+        let filter = TraceFilter {
+            fields: vec![("worry".to_string(), "500.0".to_string())],
+            ..Default::default()
+        };
+        assert_eq!(filter.filter_lines(TRACE).len(), 1);
+        let filter = TraceFilter {
+            fields: vec![("missing".to_string(), "1".to_string())],
+            ..Default::default()
+        };
+        assert!(filter.filter_lines(TRACE).is_empty());
+    }
+}
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+pub(crate) const YEAR: i32 = 2022;
+// puzzles open at midnight US Eastern,
+// December is always EST (UTC-5)
+const UNLOCK_HOUR_UTC: u32 = 5;
+
+// None for the days outside the calendar
+pub(crate) fn unlock_instant(day: u8) -> Option<DateTime<Utc>> {
+    if !(1..=25).contains(&day) {
+        return None;
+    }
+    Utc.with_ymd_and_hms(YEAR, 12, day as u32, UNLOCK_HOUR_UTC, 0, 0)
+        .single()
+}
+
+pub(crate) fn time_until_unlock(unlock: DateTime<Utc>, now: DateTime<Utc>) -> Option<Duration> {
+    let remaining = unlock - now;
+    if remaining > Duration::zero() {
+        Some(remaining)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn format_countdown(remaining: Duration) -> String {
+    let total_seconds = remaining.num_seconds();
+    let days = total_seconds / 86_400;
+    let hours = (total_seconds % 86_400) / 3_600;
+    let minutes = (total_seconds % 3_600) / 60;
+    let seconds = total_seconds % 60;
+    if days > 0 {
+        format!("{}d {:02}:{:02}:{:02}", days, hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+}
+
+#[cfg(test)]
+mod unlock_test {
+    use super::*;
+
+    #[test]
+    fn it_can_compute_unlock_instant() {
+        let expected = Utc.with_ymd_and_hms(2022, 12, 15, 5, 0, 0).unwrap();
+        assert_eq!(unlock_instant(15), Some(expected));
+    }
+
+    #[test]
+    fn it_refuses_days_outside_calendar() {
+        assert_eq!(unlock_instant(0), None);
+        assert_eq!(unlock_instant(26), None);
+        assert!(unlock_instant(25).is_some());
+    }
+
+    #[test]
+    fn it_can_compute_time_until_unlock() {
+        let unlock = unlock_instant(15).unwrap();
+        let before = Utc.with_ymd_and_hms(2022, 12, 15, 4, 30, 0).unwrap();
+        assert_eq!(time_until_unlock(unlock, before), Some(Duration::minutes(30)));
+        let at = Utc.with_ymd_and_hms(2022, 12, 15, 5, 0, 0).unwrap();
+        assert_eq!(time_until_unlock(unlock, at), None);
+        let after = Utc.with_ymd_and_hms(2022, 12, 16, 0, 0, 0).unwrap();
+        assert_eq!(time_until_unlock(unlock, after), None);
+    }
+
+    #[test]
+    fn it_can_format_countdown() {
+        assert_eq!(format_countdown(Duration::seconds(59)), "00:00:59");
+        assert_eq!(format_countdown(Duration::seconds(3_661)), "01:01:01");
+        assert_eq!(format_countdown(Duration::seconds(90_061)), "1d 01:01:01");
+    }
+}
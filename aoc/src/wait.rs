@@ -0,0 +1,214 @@
+use crate::client::{fetch_with_retries, AdventSite, FetchError, RetryPolicy};
+use crate::clock::Clock;
+use crate::journal::{record, JournalEntry, JournalEvent};
+use crate::scaffold::{cached_input_path, cached_statement_path, scaffold_day, write_cache};
+use crate::unlock::{format_countdown, time_until_unlock, unlock_instant};
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+const COUNTDOWN_TICK: Duration = Duration::from_secs(1);
+
+pub(crate) fn wait_for_unlock(clock: &dyn Clock, day: u8) -> Result<(), FetchError> {
+    let unlock = unlock_instant(day).ok_or(FetchError::NoPuzzle(day))?;
+    println!("Day {} unlocks at {}", day, unlock);
+    while let Some(remaining) = time_until_unlock(unlock, clock.now()) {
+        print!(
+            "\rDay {} unlocks in {}   ",
+            day,
+            format_countdown(remaining)
+        );
+        std::io::stdout().flush().unwrap();
+        let step = remaining.to_std().unwrap().min(COUNTDOWN_TICK);
+        clock.sleep(step);
+    }
+    println!("\rDay {} is open!                  ", day);
+    Ok(())
+}
+
+pub(crate) fn wait_and_fetch(
+    clock: &dyn Clock,
+    site: &dyn AdventSite,
+    policy: &RetryPolicy,
+    root: &Path,
+    day: u8,
+) -> Result<(), FetchError> {
+    wait_for_unlock(clock, day)?;
+
+    let input = fetch_with_retries(clock, policy, || site.input(day))?;
+    let input_path = cached_input_path(root, day);
+    write_cache(&input_path, &input).unwrap();
+    println!("Input saved to {:?}", input_path);
+    record(
+        root,
+        JournalEntry {
+            at: clock.now(),
+            day,
+            event: JournalEvent::InputFetched,
+        },
+    )
+    .unwrap();
+
+    let statement = fetch_with_retries(clock, policy, || site.statement(day))?;
+    let statement_path = cached_statement_path(root, day);
+    write_cache(&statement_path, &statement).unwrap();
+    println!("Statement saved to {:?}", statement_path);
+
+    match scaffold_day(root, day).unwrap() {
+        Some(created) => println!("Crate created in {:?}", created),
+        None => println!("Crate for day {} already exists", day),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod wait_test {
+    use super::*;
+    use crate::client::HttpAdventSite;
+    use crate::clock::FakeClock;
+    use advent_common::mock_server::MockAdventServer;
+    use chrono::{TimeZone, Utc};
+    use std::cell::{Cell, RefCell};
+    use std::fs;
+
+    // answers 404 until the clock reaches the unlock time
+    struct LockedUntilUnlock<'a> {
+        clock: &'a FakeClock,
+        requested_at: RefCell<Vec<chrono::DateTime<Utc>>>,
+        statements: Cell<u32>,
+    }
+
+    impl AdventSite for LockedUntilUnlock<'_> {
+        fn input(&self, day: u8) -> Result<String, FetchError> {
+            let now = self.clock.now();
+            self.requested_at.borrow_mut().push(now);
+            if now < unlock_instant(day).unwrap() {
+                Err(FetchError::NotYetUnlocked)
+            } else {
+                Ok("498,4 -> 498,6\n".to_string())
+            }
+        }
+
+        fn statement(&self, _day: u8) -> Result<String, FetchError> {
+            self.statements.set(self.statements.get() + 1);
+            Ok("<main>--- Day 15 ---</main>".to_string())
+        }
+
+        fn answer(&self, _day: u8, _part: u8, _answer: &str) -> Result<String, FetchError> {
+            unreachable!()
+        }
+    }
+
+    fn fake_root() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let template = root.path().join("template");
+        fs::create_dir_all(template.join("src")).unwrap();
+        fs::write(template.join("Cargo.toml"), "name = \"day-XX\"\n").unwrap();
+        fs::write(template.join("src/main.rs"), "const DAY: u8 = ;\n").unwrap();
+        root
+    }
+
+    #[test]
+    fn it_waits_until_unlock() {
+        let start = Utc.with_ymd_and_hms(2022, 12, 15, 4, 59, 30).unwrap();
+        let clock = FakeClock::new(start);
+        wait_for_unlock(&clock, 15).unwrap();
+        assert_eq!(clock.now(), unlock_instant(15).unwrap());
+        assert_eq!(clock.total_slept(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn it_does_not_wait_once_open() {
+        let start = Utc.with_ymd_and_hms(2022, 12, 20, 0, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        wait_for_unlock(&clock, 15).unwrap();
+        assert_eq!(clock.total_slept(), Duration::ZERO);
+    }
+
+    #[test]
+    fn it_refuses_days_outside_calendar() {
+        let start = Utc.with_ymd_and_hms(2022, 12, 20, 0, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        assert_eq!(wait_for_unlock(&clock, 0), Err(FetchError::NoPuzzle(0)));
+        assert_eq!(wait_for_unlock(&clock, 26), Err(FetchError::NoPuzzle(26)));
+        assert_eq!(clock.total_slept(), Duration::ZERO);
+    }
+
+    #[test]
+    fn it_fetches_at_unlock_and_scaffolds() {
+        let root = fake_root();
+        let start = Utc.with_ymd_and_hms(2022, 12, 15, 4, 59, 58).unwrap();
+        let clock = FakeClock::new(start);
+        let site = LockedUntilUnlock {
+            clock: &clock,
+            requested_at: RefCell::new(vec![]),
+            statements: Cell::new(0),
+        };
+        wait_and_fetch(&clock, &site, &RetryPolicy::default(), root.path(), 15).unwrap();
+
+        // never asked before the unlock
+        let requested_at = site.requested_at.borrow();
+        assert_eq!(requested_at.len(), 1);
+        assert_eq!(requested_at[0], unlock_instant(15).unwrap());
+        assert_eq!(site.statements.get(), 1);
+
+        let input = fs::read_to_string(root.path().join(".cache/day-15.txt")).unwrap();
+        assert_eq!(input, "498,4 -> 498,6\n");
+        let statement = fs::read_to_string(root.path().join(".cache/day-15.html")).unwrap();
+        assert!(statement.contains("Day 15"));
+        let main_rs = fs::read_to_string(root.path().join("day-15/src/main.rs")).unwrap();
+        assert_eq!(main_rs, "const DAY: u8 = 15;\n");
+
+        let journal = crate::journal::read_journal(root.path()).unwrap();
+        assert_eq!(journal.len(), 1);
+        assert_eq!(journal[0].event, JournalEvent::InputFetched);
+        assert_eq!(journal[0].at, unlock_instant(15).unwrap());
+    }
+
+    #[test]
+    fn it_stops_on_fatal_error() {
+        struct BadCookie;
+        impl AdventSite for BadCookie {
+            fn input(&self, _day: u8) -> Result<String, FetchError> {
+                Err(FetchError::Status(400))
+            }
+            fn statement(&self, _day: u8) -> Result<String, FetchError> {
+                unreachable!()
+            }
+            fn answer(&self, _day: u8, _part: u8, _answer: &str) -> Result<String, FetchError> {
+                unreachable!()
+            }
+        }
+        let root = fake_root();
+        let clock = FakeClock::new(unlock_instant(15).unwrap());
+        let result = wait_and_fetch(&clock, &BadCookie, &RetryPolicy::default(), root.path(), 15);
+        assert_eq!(result, Err(FetchError::Status(400)));
+        assert!(!root.path().join("day-15").exists());
+    }
+
+    #[test]
+    fn it_fetches_from_mock_server() {
+        let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        let server = MockAdventServer::new(&fixtures).serve("127.0.0.1:0");
+        let site = HttpAdventSite::new(&server.base_url, "session=abc");
+        let root = fake_root();
+
+        let clock = FakeClock::new(unlock_instant(1).unwrap());
+        wait_and_fetch(&clock, &site, &RetryPolicy::default(), root.path(), 1).unwrap();
+        let input = fs::read_to_string(root.path().join(".cache/day-01.txt")).unwrap();
+        assert!(input.ends_with("10000\n"));
+        let statement = fs::read_to_string(root.path().join(".cache/day-01.html")).unwrap();
+        assert!(statement.contains("Calorie Counting"));
+        assert!(root.path().join("day-01/src/main.rs").exists());
+
+        // no fixture for that day: still locked after every retry
+        let clock = FakeClock::new(unlock_instant(25).unwrap());
+        let policy = RetryPolicy {
+            max_attempts: 2,
+            ..RetryPolicy::default()
+        };
+        let result = wait_and_fetch(&clock, &site, &policy, root.path(), 25);
+        assert_eq!(result, Err(FetchError::NotYetUnlocked));
+        assert!(!root.path().join(".cache/day-25.txt").exists());
+    }
+}
@@ -0,0 +1,235 @@
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::path::{Path, PathBuf};
+
+/// Calories carried by one elf, `elf` being its position in the input
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub struct ElfTotal {
+    pub elf: usize,
+    pub calories: u64,
+}
+
+/// Total calories of every elf, in input order
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ElfInventory {
+    totals: Vec<u64>,
+}
+
+// summed as u64, a few big snacks would overflow a u32
+impl From<Vec<Vec<u32>>> for ElfInventory {
+    fn from(item: Vec<Vec<u32>>) -> Self {
+        ElfInventory {
+            totals: item
+                .into_iter()
+                .map(|snacks| snacks.into_iter().map(u64::from).sum())
+                .collect(),
+        }
+    }
+}
+
+impl ElfInventory {
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    pub fn totals(&self) -> Vec<ElfTotal> {
+        self.totals
+            .iter()
+            .enumerate()
+            .map(|(elf, calories)| ElfTotal {
+                elf,
+                calories: *calories,
+            })
+            .collect()
+    }
+
+    /// The `k` richest elves, richest first, the first elf in the input wins a tie.
+    /// Keeps a heap of `k` elves, O(n log k)
+    pub fn top_k(&self, k: usize) -> Vec<ElfTotal> {
+        // min-heap on (calories, later elf first): its top is the one to drop
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for (elf, calories) in self.totals.iter().enumerate() {
+            heap.push(Reverse((*calories, Reverse(elf))));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(elf)))| ElfTotal { elf, calories })
+            .collect()
+    }
+
+    /// Same as `top_k`, plus the elves carrying as much as the last one kept
+    pub fn top_k_with_ties(&self, k: usize) -> Vec<ElfTotal> {
+        let mut top = self.top_k(k);
+        if let Some(last) = top.last().copied() {
+            for (elf, calories) in self.totals.iter().enumerate() {
+                if *calories == last.calories && !top.iter().any(|t| t.elf == elf) {
+                    top.push(ElfTotal {
+                        elf,
+                        calories: *calories,
+                    });
+                }
+            }
+        }
+        top
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let sum: u64 = self.totals.iter().sum();
+        Some(sum as f64 / self.len() as f64)
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// Value below which `p` percent of the elves are, interpolated between two elves.
+    /// Panics if `p` is not within 0 and 100
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        assert!((0.0..=100.0).contains(&p), "percentile out of range: {}", p);
+        if self.is_empty() {
+            return None;
+        }
+        let mut sorted = self.totals.clone();
+        sorted.sort_unstable();
+        let rank = p / 100.0 * (sorted.len() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        let weight = rank - lower as f64;
+        Some(sorted[lower] as f64 * (1.0 - weight) + sorted[upper] as f64 * weight)
+    }
+
+    /// "elf,calories" then one line per elf
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("elf,calories\n");
+        for total in self.totals() {
+            csv.push_str(&format!("{},{}\n", total.elf, total.calories));
+        }
+        csv
+    }
+
+    /// `[{"elf":0,"calories":6000},...]`
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.totals()).unwrap()
+    }
+
+    /// Writes the totals as JSON if `path` ends with `.json`, as CSV otherwise
+    pub fn export(&self, path: &Path) {
+        let content = match path.extension() {
+            Some(extension) if extension == "json" => self.to_json(),
+            _ => self.to_csv(),
+        };
+        std::fs::write(path, content).unwrap();
+    }
+}
+
+/// `--export PATH` on the command line
+pub fn export_path_from_args(args: &[String]) -> Option<PathBuf> {
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--export" {
+            return iter.next().map(PathBuf::from);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod inventory_test {
+    use super::*;
+
+    fn example() -> ElfInventory {
+        ElfInventory::from(vec![
+            vec![1000, 2000, 3000],
+            vec![4000],
+            vec![5000, 6000],
+            vec![7000, 8000, 9000],
+            vec![10000],
+        ])
+    }
+
+    fn elves(totals: &[ElfTotal]) -> Vec<usize> {
+        totals.iter().map(|t| t.elf).collect()
+    }
+
+    #[test]
+    fn it_can_find_top_k() {
+        let inventory = example();
+        assert_eq!(
+            inventory.top_k(3),
+            vec![
+                ElfTotal { elf: 3, calories: 24000 },
+                ElfTotal { elf: 2, calories: 11000 },
+                ElfTotal { elf: 4, calories: 10000 },
+            ]
+        );
+        assert_eq!(elves(&inventory.top_k(10)), vec![3, 2, 4, 0, 1]);
+        assert!(inventory.top_k(0).is_empty());
+    }
+
+    #[test]
+    fn it_can_handle_ties() {
+        let inventory = ElfInventory::from(vec![vec![5], vec![9], vec![5], vec![7], vec![5]]);
+        assert_eq!(elves(&inventory.top_k(3)), vec![1, 3, 0]);
+        assert_eq!(elves(&inventory.top_k_with_ties(3)), vec![1, 3, 0, 2, 4]);
+        assert_eq!(elves(&inventory.top_k_with_ties(2)), vec![1, 3]);
+    }
+
+    #[test]
+    fn it_can_compute_statistics() {
+        let inventory = example();
+        assert_eq!(inventory.mean(), Some(11000.0));
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(inventory.percentile(0.0), Some(4000.0));
+        assert_eq!(inventory.percentile(100.0), Some(24000.0));
+        assert_eq!(inventory.percentile(75.0), Some(11000.0));
+        assert_eq!(inventory.percentile(12.5), Some(5000.0));
+        let even = ElfInventory::from(vec![vec![1], vec![2], vec![3], vec![4]]);
+        assert_eq!(even.median(), Some(2.5));
+        assert_eq!(ElfInventory::default().mean(), None);
+        assert_eq!(ElfInventory::default().median(), None);
+    }
+
+    #[test]
+    fn it_can_export() {
+        let inventory = ElfInventory::from(vec![vec![1000, 2000], vec![4000]]);
+        assert_eq!(inventory.to_csv(), "elf,calories\n0,3000\n1,4000\n");
+        assert_eq!(
+            inventory.to_json(),
+            r#"[{"elf":0,"calories":3000},{"elf":1,"calories":4000}]"#
+        );
+
+        let dir = std::env::temp_dir().join(format!("day-01-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        inventory.export(&dir.join("totals.json"));
+        inventory.export(&dir.join("totals.csv"));
+        assert!(std::fs::read_to_string(dir.join("totals.json")).unwrap().starts_with('['));
+        assert!(std::fs::read_to_string(dir.join("totals.csv")).unwrap().starts_with("elf,"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn it_can_total_beyond_u32() {
+        let inventory = ElfInventory::from(vec![vec![u32::MAX, u32::MAX], vec![1]]);
+        assert_eq!(inventory.top_k(1)[0].calories, 2 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn it_can_read_export_flag() {
+        let args: Vec<String> = ["day", "--stdin", "--export", "totals.csv"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(export_path_from_args(&args), Some(PathBuf::from("totals.csv")));
+        assert_eq!(export_path_from_args(&args[..2]), None);
+    }
+}
@@ -0,0 +1,112 @@
+//! Day 1: Calorie Counting, the snacks carried by each elf.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub mod inventory;
+pub mod streaming;
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+pub use inventory::{ElfInventory, ElfTotal};
+pub use streaming::{stream_solve_one, stream_solve_two, StreamError, StreamingTotals};
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 1;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    ElfCalories { elf: usize, calories: u64 },
+}
+
+/// Splits the input into the calories of each elf
+pub fn parse_input(puzzle_input: String) -> Vec<Vec<u32>> {
+    let mut result = vec![];
+    let mut buffer = vec![];
+    for row in puzzle_input.split("\n") {
+        match row {
+            "" => {
+                result.push(buffer);
+                buffer = vec![];
+            },
+            x => buffer.push(x.parse::<u32>().unwrap())
+        }
+    }
+    result
+}
+
+/// Calories carried by the richest elf
+pub fn solve_one(parsed: Vec<Vec<u32>>) -> u64 {
+    let inventory = ElfInventory::from(parsed);
+    for total in inventory.totals() {
+        trace::emit(TraceEvent::ElfCalories { elf: total.elf, calories: total.calories });
+    }
+    inventory.top_k(1)[0].calories
+}
+
+/// Calories carried by the top three elves
+pub fn solve_two(parsed: Vec<Vec<u32>>) -> u64 {
+    ElfInventory::from(parsed)
+        .top_k(3)
+        .iter()
+        .map(|total| total.calories)
+        .sum()
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts,
+/// `--export PATH` also writes the total of each elf (CSV, or JSON for a `.json` path),
+/// `--stream` reads a file or stdin line by line instead, for inputs too big for memory
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|arg| arg == "--stream") {
+        // one pass for both parts: the top three also holds the best elf
+        let streamed = streaming::open_input(&source, DAY, advent_common::embedded_input!())
+            .and_then(|input| StreamingTotals::new(3).read(input))
+            .and_then(|totals| Ok((totals.best().first().copied(), totals.best_sum()?)));
+        match streamed {
+            Ok((best, best_sum)) => {
+                // an empty inventory carries nothing
+                println!("First solution: {:?}", best.unwrap_or(0));
+                println!("Second solution: {:?}", best_sum);
+            }
+            Err(error) => {
+                eprintln!("{:?}", error);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed.clone());
+    println!("Second solution: {:?}", second_solution);
+    if let Some(path) = inventory::export_path_from_args(&args) {
+        ElfInventory::from(parsed).export(&path);
+    }
+}
+
+#[cfg(test)]
+mod day1_test {
+    use super::*;
+
+    #[test]
+    fn it_can_parse_example() {
+        let example =
+            String::from("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n");
+        let parsed = parse_input(example);
+        let expected = vec![
+            vec![1000, 2000, 3000],
+            vec![4000],
+            vec![5000, 6000],
+            vec![7000, 8000, 9000],
+            vec![10000],
+        ];
+        assert_eq!(parsed, expected);
+    }
+}
@@ -0,0 +1,236 @@
+use crate::InputSource;
+use advent_common::InputError;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor};
+
+/// Why a streamed inventory has no answer
+#[derive(Debug, PartialEq)]
+pub enum StreamError {
+    Io(String),
+    // line numbers start at 1
+    Parse { line: usize, text: String },
+    // the total of an elf does not fit in a u64
+    Overflow { line: usize },
+    Input(InputError),
+}
+
+impl From<io::Error> for StreamError {
+    fn from(item: io::Error) -> Self {
+        StreamError::Io(item.to_string())
+    }
+}
+
+impl From<InputError> for StreamError {
+    fn from(item: InputError) -> Self {
+        StreamError::Input(item)
+    }
+}
+
+/// Reads an inventory line by line, keeping only the total of the current elf
+/// and the `k` best totals seen so far, so memory does not grow with the input
+#[derive(Debug, PartialEq, Clone)]
+pub struct StreamingTotals {
+    k: usize,
+    // richest first, at most k
+    best: Vec<u64>,
+    current: u64,
+    line: usize,
+    elves: usize,
+}
+
+impl StreamingTotals {
+    pub fn new(k: usize) -> Self {
+        StreamingTotals {
+            k,
+            best: Vec::with_capacity(k + 1),
+            current: 0,
+            line: 0,
+            elves: 0,
+        }
+    }
+
+    fn close_elf(&mut self) {
+        let position = self.best.partition_point(|x| *x >= self.current);
+        if position < self.k {
+            self.best.insert(position, self.current);
+            self.best.truncate(self.k);
+        }
+        self.current = 0;
+        self.elves += 1;
+    }
+
+    // a piece of the input between two "\n", like the items of `split("\n")`
+    fn push_segment(&mut self, segment: &str) -> Result<(), StreamError> {
+        self.line += 1;
+        if segment.is_empty() {
+            self.close_elf();
+            return Ok(());
+        }
+        let calories = segment.parse::<u64>().map_err(|_| StreamError::Parse {
+            line: self.line,
+            text: segment.to_string(),
+        })?;
+        self.current = self
+            .current
+            .checked_add(calories)
+            .ok_or(StreamError::Overflow { line: self.line })?;
+        Ok(())
+    }
+
+    /// Consumes the whole reader. As with `parse_input`, an elf is only counted once
+    /// followed by an empty line or the final newline
+    pub fn read<R: BufRead>(mut self, mut reader: R) -> Result<Self, StreamError> {
+        let mut buffer = String::new();
+        // an empty input is one empty segment
+        let mut ended_with_newline = true;
+        loop {
+            buffer.clear();
+            if reader.read_line(&mut buffer)? == 0 {
+                break;
+            }
+            ended_with_newline = buffer.ends_with('\n');
+            self.push_segment(buffer.strip_suffix('\n').unwrap_or(&buffer))?;
+        }
+        if ended_with_newline {
+            self.push_segment("")?;
+        }
+        Ok(self)
+    }
+
+    /// Best totals, richest first
+    pub fn best(&self) -> &[u64] {
+        &self.best
+    }
+
+    pub fn elves(&self) -> usize {
+        self.elves
+    }
+
+    /// Sum of the best totals, checked as well
+    pub fn best_sum(&self) -> Result<u64, StreamError> {
+        self.best
+            .iter()
+            .try_fold(0u64, |sum, x| sum.checked_add(*x))
+            .ok_or(StreamError::Overflow { line: self.line })
+    }
+}
+
+/// Same answer as `solve_one`, without loading the input
+pub fn stream_solve_one<R: BufRead>(reader: R) -> Result<u64, StreamError> {
+    StreamingTotals::new(1).read(reader)?.best_sum()
+}
+
+/// Same answer as `solve_two`, without loading the input
+pub fn stream_solve_two<R: BufRead>(reader: R) -> Result<u64, StreamError> {
+    StreamingTotals::new(3).read(reader)?.best_sum()
+}
+
+/// A file or stdin is read as it goes, the other sources are loaded first,
+/// an error if the file cannot be opened or the input read
+pub fn open_input(
+    source: &InputSource,
+    day: u8,
+    embedded: Option<&'static str>,
+) -> Result<Box<dyn BufRead>, StreamError> {
+    Ok(match source {
+        InputSource::Path(path) => Box::new(BufReader::new(File::open(path)?)),
+        InputSource::Stdin => Box::new(BufReader::new(io::stdin())),
+        other => Box::new(Cursor::new(other.read_input(day, embedded)?)),
+    })
+}
+
+#[cfg(test)]
+mod streaming_test {
+    use super::*;
+    use crate::{parse_input, solve_one, solve_two};
+    use std::io::Read;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    // "1\n1\n...\n\n" for ever, without storing it
+    struct Repeated {
+        pattern: &'static [u8],
+        remaining: usize,
+        position: usize,
+    }
+
+    impl Read for Repeated {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let mut written = 0;
+            while written < buf.len() && self.remaining > 0 {
+                buf[written] = self.pattern[self.position];
+                written += 1;
+                self.position = (self.position + 1) % self.pattern.len();
+                if self.position == 0 {
+                    self.remaining -= 1;
+                }
+            }
+            Ok(written)
+        }
+    }
+
+    #[test]
+    fn it_can_solve_example() {
+        assert_eq!(stream_solve_one(EXAMPLE.as_bytes()), Ok(24000));
+        assert_eq!(stream_solve_two(EXAMPLE.as_bytes()), Ok(45000));
+        let totals = StreamingTotals::new(3).read(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(totals.best(), &[24000, 11000, 10000]);
+        assert_eq!(totals.elves(), 5);
+    }
+
+    #[test]
+    fn it_agrees_with_the_loaded_solvers() {
+        // a small generator, the same inputs every run
+        let mut seed: u64 = 2022;
+        let mut next = |modulo: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % modulo
+        };
+        for _ in 0..200 {
+            let mut input = String::new();
+            for _ in 0..(3 + next(20)) {
+                for _ in 0..next(6) {
+                    input.push_str(&format!("{}\n", next(100000)));
+                }
+                input.push('\n');
+            }
+            if next(2) == 0 {
+                // no final newline: the last elf is not counted by either
+                input.push_str("42");
+            }
+            let parsed = parse_input(input.clone());
+            assert_eq!(stream_solve_one(input.as_bytes()), Ok(solve_one(parsed.clone())));
+            assert_eq!(stream_solve_two(input.as_bytes()), Ok(solve_two(parsed)));
+        }
+    }
+
+    #[test]
+    fn it_reports_overflow_and_bad_lines() {
+        let big = format!("{}\n1\n\n", u64::MAX);
+        assert_eq!(stream_solve_one(big.as_bytes()), Err(StreamError::Overflow { line: 2 }));
+        let over_u32 = format!("{}\n{}\n\n", u32::MAX, u32::MAX);
+        assert_eq!(stream_solve_one(over_u32.as_bytes()), Ok(2 * u32::MAX as u64));
+        assert_eq!(
+            stream_solve_one("12\nabc\n".as_bytes()),
+            Err(StreamError::Parse {
+                line: 2,
+                text: "abc".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_can_stream_large_inputs() {
+        // 2 million elves carrying 3 snacks of 1, then one carrying 5
+        let reader = Repeated {
+            pattern: b"1\n1\n1\n\n",
+            remaining: 2_000_000,
+            position: 0,
+        }
+        .chain("5\n\n".as_bytes());
+        let totals = StreamingTotals::new(3).read(BufReader::new(reader)).unwrap();
+        assert_eq!(totals.best(), &[5, 3, 3]);
+        // the empty segment after the final newline closes one more, empty, elf
+        assert_eq!(totals.elves(), 2_000_002);
+    }
+}
@@ -0,0 +1,67 @@
+// Fetches the input from a mock website instead of adventofcode.com
+#![cfg(feature = "network")]
+
+use advent_common::input_source::{cached_or_fetched, fetch_input, InputError};
+use advent_common::mock_server::{MockAdventServer, RunningServer};
+use day_01::{parse_input, solve_one};
+use std::fs;
+use std::path::Path;
+
+const COOKIE: &str = "session=mock";
+
+// day 1 has an input, day 2 is rate limited, day 25 is still locked
+fn mock_server(dir: &Path) -> RunningServer {
+    fs::create_dir_all(dir.join("2022/day/1")).unwrap();
+    fs::create_dir_all(dir.join("2022/day/2")).unwrap();
+    let example = include_str!("../examples/example.txt");
+    fs::write(dir.join("2022/day/1/input"), example).unwrap();
+    fs::write(
+        dir.join("2022/day/2/input"),
+        "You have made too many requests",
+    )
+    .unwrap();
+    fs::write(dir.join("2022/day/2/input.status"), "429").unwrap();
+    MockAdventServer::new(dir).serve("127.0.0.1:0")
+}
+
+#[test]
+fn it_fetches_and_caches_inputs() {
+    let dir = std::env::temp_dir().join(format!("day-01-network-{}", std::process::id()));
+    let server = mock_server(&dir);
+    let url = |day: u8| format!("{}/2022/day/{}/input", server.base_url, day);
+
+    let input = fetch_input(&server.base_url, COOKIE, 1).unwrap();
+    assert_eq!(solve_one(parse_input(input)), 24000);
+    assert_eq!(
+        fetch_input(&server.base_url, COOKIE, 25),
+        Err(InputError::Status {
+            url: url(25),
+            status: 404
+        })
+    );
+    assert_eq!(
+        fetch_input(&server.base_url, COOKIE, 2),
+        Err(InputError::Status {
+            url: url(2),
+            status: 429
+        })
+    );
+
+    // error pages are not cached, inputs are
+    let cache = dir.join("cache");
+    let limited = cached_or_fetched(&cache.join("day-02.txt"), || {
+        fetch_input(&server.base_url, COOKIE, 2)
+    });
+    assert!(limited.is_err());
+    assert!(!cache.join("day-02.txt").exists());
+    let fetched = cached_or_fetched(&cache.join("day-01.txt"), || {
+        fetch_input(&server.base_url, COOKIE, 1)
+    });
+    assert_eq!(
+        fetched.unwrap(),
+        fs::read_to_string(cache.join("day-01.txt")).unwrap()
+    );
+
+    drop(server);
+    fs::remove_dir_all(&dir).unwrap();
+}
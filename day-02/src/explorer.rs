@@ -0,0 +1,186 @@
+use crate::shape::{round_score, Outcome, Shape};
+use crate::{Round, SecondColumn};
+
+/// A way to read the second column of the guide, X, Y and Z in that order
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Interpretation {
+    Shapes([Shape; 3]),
+    Outcomes([Outcome; 3]),
+}
+
+fn permutations<T: Copy>(items: [T; 3]) -> Vec<[T; 3]> {
+    let [a, b, c] = items;
+    vec![
+        [a, b, c],
+        [a, c, b],
+        [b, a, c],
+        [b, c, a],
+        [c, a, b],
+        [c, b, a],
+    ]
+}
+
+fn column_index(column: SecondColumn) -> usize {
+    match column {
+        SecondColumn::X => 0,
+        SecondColumn::Y => 1,
+        SecondColumn::Z => 2,
+    }
+}
+
+impl Interpretation {
+    /// The 6 ways to give a shape to each letter, then the 6 ways to give an outcome,
+    /// the puzzle readings come first in their group
+    pub fn all() -> Vec<Interpretation> {
+        let shapes = permutations(Shape::ALL)
+            .into_iter()
+            .map(Interpretation::Shapes);
+        let outcomes = permutations(Outcome::ALL)
+            .into_iter()
+            .map(Interpretation::Outcomes);
+        shapes.chain(outcomes).collect()
+    }
+
+    /// e.g. "X=Rock Y=Paper Z=Scissors"
+    pub fn label(&self) -> String {
+        let names: Vec<String> = match self {
+            Interpretation::Shapes(shapes) => shapes.iter().map(|s| format!("{:?}", s)).collect(),
+            Interpretation::Outcomes(outcomes) => {
+                outcomes.iter().map(|o| format!("{:?}", o)).collect()
+            }
+        };
+        format!("X={} Y={} Z={}", names[0], names[1], names[2])
+    }
+
+    pub fn round_score(&self, round: &Round) -> u32 {
+        let i = column_index(round.column);
+        let me = match self {
+            Interpretation::Shapes(shapes) => shapes[i],
+            Interpretation::Outcomes(outcomes) => Shape::for_outcome(round.opponent, outcomes[i]),
+        };
+        round_score(round.opponent, me)
+    }
+
+    pub fn score(&self, guide: &[Round]) -> u32 {
+        guide.iter().map(|round| self.round_score(round)).sum()
+    }
+}
+
+/// Total of the guide under one interpretation
+#[derive(Debug, PartialEq, Clone)]
+pub struct Reading {
+    pub interpretation: Interpretation,
+    pub score: u32,
+    // the score is the known answer
+    pub matches: bool,
+}
+
+/// Scores the guide under every interpretation, `known` being an accepted answer if any
+pub fn explore(guide: &[Round], known: Option<u32>) -> Vec<Reading> {
+    Interpretation::all()
+        .into_iter()
+        .map(|interpretation| {
+            let score = interpretation.score(guide);
+            Reading {
+                interpretation,
+                score,
+                matches: known == Some(score),
+            }
+        })
+        .collect()
+}
+
+/// One line per reading, the matching ones marked
+pub fn format_readings(readings: &[Reading]) -> String {
+    readings
+        .iter()
+        .map(|reading| {
+            let mark = if reading.matches { "  <- matches" } else { "" };
+            format!(
+                "{:<40} {:>8}{}\n",
+                reading.interpretation.label(),
+                reading.score,
+                mark
+            )
+        })
+        .collect()
+}
+
+/// `--explore` on the command line, with `--expect N` for the known answer,
+/// an error if N is missing or not a number
+pub fn explore_from_args(args: &[String]) -> Result<Option<Option<u32>>, String> {
+    if !args.iter().any(|arg| arg == "--explore") {
+        return Ok(None);
+    }
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--expect" {
+            return match iter.next() {
+                Some(value) => value
+                    .parse()
+                    .map(|known| Some(Some(known)))
+                    .map_err(|_| format!("--expect expects a number, got {:?}", value)),
+                None => Err("--expect expects a number".to_string()),
+            };
+        }
+    }
+    Ok(Some(None))
+}
+
+#[cfg(test)]
+mod explorer_test {
+    use super::*;
+    use crate::{parse_input, solve_one, solve_two};
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn it_lists_every_interpretation() {
+        let all = Interpretation::all();
+        assert_eq!(all.len(), 12);
+        for (i, a) in all.iter().enumerate() {
+            assert!(all[i + 1..].iter().all(|b| b != a));
+        }
+        assert_eq!(all[0].label(), "X=Rock Y=Paper Z=Scissors");
+        assert_eq!(all[6].label(), "X=Lose Y=Draw Z=Win");
+    }
+
+    #[test]
+    fn it_finds_the_puzzle_readings() {
+        let guide = parse_input(EXAMPLE.to_string());
+        let readings = explore(&guide, Some(15));
+        assert_eq!(readings[0].score, solve_one(guide.clone()));
+        assert_eq!(readings[6].score, solve_two(guide.clone()));
+        assert!(readings[0].matches);
+        let scores: Vec<u32> = readings.iter().map(|r| r.score).collect();
+        assert_eq!(
+            scores.iter().filter(|s| **s == 15).count(),
+            readings.iter().filter(|r| r.matches).count()
+        );
+
+        let report = format_readings(&explore(&guide, Some(12)));
+        let marked: Vec<&str> = report
+            .lines()
+            .filter(|l| l.ends_with("<- matches"))
+            .collect();
+        assert!(marked.iter().any(|l| l.starts_with("X=Lose Y=Draw Z=Win")));
+    }
+
+    #[test]
+    fn it_can_read_flags() {
+        let args = |values: &[&str]| {
+            values
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(explore_from_args(&args(&["day", "--stdin"])), Ok(None));
+        assert_eq!(explore_from_args(&args(&["day", "--explore"])), Ok(Some(None)));
+        assert_eq!(
+            explore_from_args(&args(&["day", "--explore", "--expect", "15"])),
+            Ok(Some(Some(15)))
+        );
+        assert!(explore_from_args(&args(&["day", "--explore", "--expect", "x"])).is_err());
+        assert!(explore_from_args(&args(&["day", "--explore", "--expect"])).is_err());
+    }
+}
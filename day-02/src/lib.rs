@@ -0,0 +1,223 @@
+//! Day 2: Rock Paper Scissors, scoring a strategy guide.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub mod explorer;
+pub mod rules;
+pub mod shape;
+pub mod tournament;
+pub use advent_common::trace;
+pub use explorer::{explore, Interpretation, Reading};
+pub use advent_common::InputSource;
+pub use rules::{Ruleset, RulesError, ShapeRule};
+pub use shape::{round_score, Outcome, Shape};
+pub use tournament::{OpponentStats, Standing, Strategy};
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 2;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    RoundScored { part: u8, round: usize, score: u32 },
+}
+
+/// Second column of the guide, read as my shape in part 1 and as the outcome in part 2
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize)]
+pub enum SecondColumn {
+    X,
+    Y,
+    Z,
+}
+
+impl From<char> for SecondColumn {
+    fn from(item: char) -> Self {
+        match item {
+            'X' => SecondColumn::X,
+            'Y' => SecondColumn::Y,
+            'Z' => SecondColumn::Z,
+            _ => panic!("Unknown letter: {:?}", item),
+        }
+    }
+}
+
+impl SecondColumn {
+    /// First reading: X, Y and Z are what I play
+    pub fn as_shape(self) -> Shape {
+        match self {
+            SecondColumn::X => Shape::Rock,
+            SecondColumn::Y => Shape::Paper,
+            SecondColumn::Z => Shape::Scissors,
+        }
+    }
+
+    /// Second reading: X, Y and Z are how the round must end
+    pub fn as_outcome(self) -> Outcome {
+        match self {
+            SecondColumn::X => Outcome::Lose,
+            SecondColumn::Y => Outcome::Draw,
+            SecondColumn::Z => Outcome::Win,
+        }
+    }
+}
+
+/// A line of the strategy guide
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub struct Round {
+    pub opponent: Shape,
+    pub column: SecondColumn,
+}
+
+impl From<&str> for Round {
+    fn from(item: &str) -> Self {
+        let chars: Vec<char> = item.chars().collect();
+        Round {
+            opponent: Shape::from(chars[0]),
+            column: SecondColumn::from(chars[2]),
+        }
+    }
+}
+
+pub type AdventParsed = Vec<Round>;
+pub type AdventResponse = u32;
+
+/// Reads each round of the guide
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut result = vec![];
+    for row in puzzle_input.split("\n") {
+        if row.is_empty() {
+            continue
+        }
+        result.push(Round::from(row));
+    }
+    result
+}
+
+/// Score of a round when the second column is my shape
+pub fn compute_round_score(round: &Round) -> u32 {
+    round_score(round.opponent, round.column.as_shape())
+}
+
+/// Score of a round when the second column is the outcome to reach
+pub fn compute_counter(round: &Round) -> u32 {
+    let me = Shape::for_outcome(round.opponent, round.column.as_outcome());
+    round_score(round.opponent, me)
+}
+
+/// Score when XYZ is my play
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    let mut result = 0;
+    for (i, round) in parsed.iter().enumerate() {
+        let score = compute_round_score(round);
+        trace::emit(TraceEvent::RoundScored { part: 1, round: i, score });
+        result += score;
+    }
+    result
+}
+
+/// Score when XYZ is the expected outcome
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    let mut result = 0;
+    for (i, round) in parsed.iter().enumerate() {
+        let score = compute_counter(round);
+        trace::emit(TraceEvent::RoundScored { part: 2, round: i, score });
+        result += score;
+    }
+    result
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts,
+/// `--rules SPEC` scores the guide with another game instead, see [`Ruleset`],
+/// `--explore [--expect N]` scores it under every reading of the second column instead
+/// and `--tournament [--matches N] [--seed S]` plays it against other strategies
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let args: Vec<String> = std::env::args().collect();
+    // the letters of another game are not the puzzle shapes, only the ruleset reads them
+    match rules::rules_from_args(&args) {
+        Ok(Some(rules)) => {
+            for (reading, score) in [
+                ("as shapes", rules.score_as_shapes(&raw_input)),
+                ("as outcomes", rules.score_as_outcomes(&raw_input)),
+            ] {
+                match score {
+                    Ok(score) => println!("With {} shapes, {}: {}", rules.len(), reading, score),
+                    Err(error) => println!("With {} shapes, {}: {:?}", rules.len(), reading, error),
+                }
+            }
+            return;
+        }
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    }
+    // bad flags are reported before the input is read as shapes
+    let (explore_known, tournament_flags) =
+        match (explorer::explore_from_args(&args), tournament::tournament_from_args(&args)) {
+            (Ok(explore_known), Ok(tournament_flags)) => (explore_known, tournament_flags),
+            (Err(message), _) | (_, Err(message)) => {
+                eprintln!("{}", message);
+                std::process::exit(2);
+            }
+        };
+    let parsed = parse_input(raw_input);
+    if let Some(known) = explore_known {
+        print!("{}", explorer::format_readings(&explore(&parsed, known)));
+        return;
+    }
+    if let Some((matches, seed)) = tournament_flags {
+        let stats = OpponentStats::from(parsed.as_slice());
+        let strategies = tournament::default_strategies(&stats);
+        let standings = tournament::tournament(&parsed, &strategies, matches, seed);
+        print!("{}", tournament::format_report(&stats, &standings));
+        return;
+    }
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed);
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day2_test {
+    use super::*;
+
+    #[test]
+    fn it_can_parse_example() {
+        let example = String::from("A Y\nB X\nC Z");
+        let parsed = parse_input(example);
+        let expected = vec![
+            Round { opponent: Shape::Rock, column: SecondColumn::Y },
+            Round { opponent: Shape::Paper, column: SecondColumn::X },
+            Round { opponent: Shape::Scissors, column: SecondColumn::Z },
+        ];
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn it_can_compute_score() {
+        let a_y = compute_round_score(&Round::from("A Y"));
+        assert_eq!(a_y, 8);
+        let b_x = compute_round_score(&Round::from("B X"));
+        assert_eq!(b_x, 1);
+        let c_z = compute_round_score(&Round::from("C Z"));
+        assert_eq!(c_z, 6);
+    }
+
+    #[test]
+    fn it_can_find_counter() {
+        let a_y = compute_counter(&Round::from("A Y"));
+        assert_eq!(a_y, 4);
+        let b_x = compute_counter(&Round::from("B X"));
+        assert_eq!(b_x, 1);
+        let c_z = compute_counter(&Round::from("C Z"));
+        assert_eq!(c_z, 7);
+    }
+}
@@ -0,0 +1,355 @@
+use crate::shape::Outcome;
+
+/// One shape of a ruleset: its letters in both columns of the guide and its points
+#[derive(Debug, PartialEq, Clone)]
+pub struct ShapeRule {
+    pub name: String,
+    pub opponent_letter: char,
+    pub my_letter: char,
+    pub score: u32,
+}
+
+impl ShapeRule {
+    pub fn new(name: &str, opponent_letter: char, my_letter: char, score: u32) -> Self {
+        ShapeRule {
+            name: name.to_string(),
+            opponent_letter,
+            my_letter,
+            score,
+        }
+    }
+}
+
+/// Why a ruleset cannot be built or a guide cannot be scored with it
+#[derive(Debug, PartialEq)]
+pub enum RulesError {
+    // a shape of the spec is not "Name:A:X:points"
+    BadShape { shape: String },
+    EvenShapes { shapes: usize },
+    SharedLetter { first: String, second: String },
+    // lines of the guide are numbered from 1
+    BadLine { line: usize },
+    UnknownLetter { line: usize, letter: char },
+}
+
+/// A cyclic game with an odd number of shapes: in the order given,
+/// shape `i` beats shape `j` when `(i - j) mod n` is odd, so each shape
+/// beats half of the others (Paper beats Rock, Scissors beats Paper...)
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ruleset {
+    shapes: Vec<ShapeRule>,
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Ruleset::classic()
+    }
+}
+
+// one "Name:A:X:points" shape of a spec
+fn parse_shape(shape: &str) -> Result<ShapeRule, RulesError> {
+    let bad_shape = || RulesError::BadShape { shape: shape.trim().to_string() };
+    let fields: Vec<&str> = shape.trim().split(':').collect();
+    let [name, opponent, mine, score] = fields.as_slice() else {
+        return Err(bad_shape());
+    };
+    let letter = |field: &str| {
+        let mut chars = field.chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => Ok(letter),
+            _ => Err(bad_shape()),
+        }
+    };
+    let score = score.parse().map_err(|_| bad_shape())?;
+    Ok(ShapeRule::new(name, letter(opponent)?, letter(mine)?, score))
+}
+
+// a preset name, or "Name:A:X:1,Name:B:Y:2,..." (opponent letter, my letter, points)
+impl TryFrom<&str> for Ruleset {
+    type Error = RulesError;
+
+    fn try_from(item: &str) -> Result<Self, Self::Error> {
+        match item {
+            "classic" => Ok(Ruleset::classic()),
+            "rpsls" => Ok(Ruleset::rpsls()),
+            spec => Ruleset::new(spec.split(',').map(parse_shape).collect::<Result<_, _>>()?),
+        }
+    }
+}
+
+impl Ruleset {
+    /// An error unless there is an odd number of shapes with distinct letters
+    pub fn new(shapes: Vec<ShapeRule>) -> Result<Self, RulesError> {
+        if shapes.len().is_multiple_of(2) {
+            return Err(RulesError::EvenShapes { shapes: shapes.len() });
+        }
+        for (i, shape) in shapes.iter().enumerate() {
+            for other in shapes[i + 1..].iter() {
+                if shape.opponent_letter == other.opponent_letter || shape.my_letter == other.my_letter {
+                    return Err(RulesError::SharedLetter {
+                        first: shape.name.clone(),
+                        second: other.name.clone(),
+                    });
+                }
+            }
+        }
+        Ok(Ruleset { shapes })
+    }
+
+    /// The game of the puzzle: Rock, Paper, Scissors as A/X, B/Y, C/Z
+    pub fn classic() -> Self {
+        Ruleset::new(vec![
+            ShapeRule::new("Rock", 'A', 'X', 1),
+            ShapeRule::new("Paper", 'B', 'Y', 2),
+            ShapeRule::new("Scissors", 'C', 'Z', 3),
+        ])
+        .unwrap()
+    }
+
+    /// Rock, Paper, Scissors, Spock, Lizard, the last two as D/W and E/V
+    pub fn rpsls() -> Self {
+        Ruleset::new(vec![
+            ShapeRule::new("Rock", 'A', 'X', 1),
+            ShapeRule::new("Paper", 'B', 'Y', 2),
+            ShapeRule::new("Scissors", 'C', 'Z', 3),
+            ShapeRule::new("Spock", 'D', 'W', 4),
+            ShapeRule::new("Lizard", 'E', 'V', 5),
+        ])
+        .unwrap()
+    }
+
+    pub fn shapes(&self) -> &[ShapeRule] {
+        &self.shapes
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn beats(&self, shape: usize, other: usize) -> bool {
+        let n = self.len();
+        (shape + n - other) % n % 2 == 1
+    }
+
+    /// What happens to me playing `me` against `opponent`, both indexes of `shapes`
+    pub fn outcome(&self, opponent: usize, me: usize) -> Outcome {
+        if me == opponent {
+            Outcome::Draw
+        } else if self.beats(me, opponent) {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    pub fn round_score(&self, opponent: usize, me: usize) -> u32 {
+        self.shapes[me].score + self.outcome(opponent, me).score()
+    }
+
+    /// The shape to play against `opponent` to get `outcome`,
+    /// the one worth the most points when several do
+    pub fn shape_for_outcome(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.len())
+            .filter(|me| self.outcome(opponent, *me) == outcome)
+            .max_by_key(|me| self.shapes[*me].score)
+            .unwrap()
+    }
+
+    pub fn opponent_shape(&self, letter: char) -> Option<usize> {
+        self.shapes.iter().position(|s| s.opponent_letter == letter)
+    }
+
+    pub fn my_shape(&self, letter: char) -> Option<usize> {
+        self.shapes.iter().position(|s| s.my_letter == letter)
+    }
+
+    /// The outcome of a second column letter in the part 2 reading:
+    /// my letters of the first three shapes are lose, draw and win, as X, Y and Z
+    pub fn outcome_for_letter(&self, letter: char) -> Option<Outcome> {
+        let outcomes = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+        self.shapes
+            .iter()
+            .take(3)
+            .position(|s| s.my_letter == letter)
+            .map(|i| outcomes[i])
+    }
+
+    // (line, opponent shape, second letter) of each line of the guide
+    fn guide_rounds(&self, guide: &str) -> Result<Vec<(usize, usize, char)>, RulesError> {
+        let mut rounds = vec![];
+        for (i, line) in guide.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let chars: Vec<char> = line.chars().collect();
+            let [opponent, ' ', second] = chars.as_slice() else {
+                return Err(RulesError::BadLine { line: i + 1 });
+            };
+            let opponent = self
+                .opponent_shape(*opponent)
+                .ok_or(RulesError::UnknownLetter { line: i + 1, letter: *opponent })?;
+            rounds.push((i + 1, opponent, *second));
+        }
+        Ok(rounds)
+    }
+
+    /// Part 1 reading: the second column is my shape
+    pub fn score_as_shapes(&self, guide: &str) -> Result<u32, RulesError> {
+        let mut score = 0;
+        for (line, opponent, letter) in self.guide_rounds(guide)? {
+            let me = self.my_shape(letter).ok_or(RulesError::UnknownLetter { line, letter })?;
+            score += self.round_score(opponent, me);
+        }
+        Ok(score)
+    }
+
+    /// Part 2 reading: the second column is the outcome, see `outcome_for_letter`
+    pub fn score_as_outcomes(&self, guide: &str) -> Result<u32, RulesError> {
+        let mut score = 0;
+        for (line, opponent, letter) in self.guide_rounds(guide)? {
+            let outcome = self
+                .outcome_for_letter(letter)
+                .ok_or(RulesError::UnknownLetter { line, letter })?;
+            score += self.round_score(opponent, self.shape_for_outcome(opponent, outcome));
+        }
+        Ok(score)
+    }
+}
+
+/// `--rules SPEC` on the command line, see `TryFrom<&str>`,
+/// an error if SPEC is missing or not a ruleset
+pub fn rules_from_args(args: &[String]) -> Result<Option<Ruleset>, String> {
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--rules" {
+            return match iter.next() {
+                Some(spec) => Ruleset::try_from(spec.as_str())
+                    .map(Some)
+                    .map_err(|error| format!("--rules {:?}: {:?}", spec, error)),
+                None => Err("--rules expects a preset or a spec".to_string()),
+            };
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod rules_test {
+    use super::*;
+    use crate::{parse_input, solve_one, solve_two};
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn it_plays_a_balanced_tournament() {
+        for rules in [Ruleset::classic(), Ruleset::rpsls()] {
+            let n = rules.len();
+            for shape in 0..n {
+                let wins = (0..n).filter(|other| rules.beats(shape, *other)).count();
+                assert_eq!(wins, (n - 1) / 2);
+                assert!(!rules.beats(shape, shape));
+                for other in 0..n {
+                    if other != shape {
+                        assert_ne!(rules.beats(shape, other), rules.beats(other, shape));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn it_knows_who_beats_who_in_rpsls() {
+        let rules = Ruleset::rpsls();
+        let shape = |name: &str| rules.shapes().iter().position(|s| s.name == name).unwrap();
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert!(rules.beats(shape(winner), shape(loser)), "{} beats {}", winner, loser);
+        }
+    }
+
+    #[test]
+    fn it_agrees_with_the_classic_game() {
+        let rules = Ruleset::default();
+        assert_eq!(rules.score_as_shapes(EXAMPLE), Ok(15));
+        assert_eq!(rules.score_as_outcomes(EXAMPLE), Ok(12));
+        let mut guide = String::new();
+        for opponent in ['A', 'B', 'C'] {
+            for column in ['X', 'Y', 'Z'] {
+                guide.push_str(&format!("{} {}\n", opponent, column));
+            }
+        }
+        assert_eq!(rules.score_as_shapes(&guide), Ok(solve_one(parse_input(guide.clone()))));
+        assert_eq!(rules.score_as_outcomes(&guide), Ok(solve_two(parse_input(guide.clone()))));
+    }
+
+    #[test]
+    fn it_can_read_a_ruleset() {
+        assert_eq!(Ruleset::try_from("rpsls"), Ok(Ruleset::rpsls()));
+        let custom = Ruleset::try_from("Rock:R:X:10, Paper:P:Y:20, Scissors:S:Z:30").unwrap();
+        assert_eq!(custom.shapes()[2], ShapeRule::new("Scissors", 'S', 'Z', 30));
+        // rock against paper, I lose
+        assert_eq!(custom.score_as_shapes("P X\n"), Ok(10));
+        assert_eq!(custom.score_as_outcomes("P Z\n"), Ok(36));
+    }
+
+    #[test]
+    fn it_reads_outcomes_from_the_configured_letters() {
+        let custom = Ruleset::try_from("Rock:R:M:1,Paper:P:N:2,Scissors:S:O:3").unwrap();
+        // M, N and O are lose, draw and win like X, Y and Z
+        assert_eq!(custom.score_as_outcomes("R M\nR N\nR O\n"), Ok(3 + 4 + 8));
+        assert_eq!(custom.score_as_shapes("R M\n"), Ok(4));
+        assert_eq!(
+            custom.score_as_outcomes("R X\n"),
+            Err(RulesError::UnknownLetter { line: 1, letter: 'X' })
+        );
+
+        let rpsls = Ruleset::rpsls();
+        assert_eq!(rpsls.score_as_shapes("D W\nE V\n"), Ok(4 + 3 + 5 + 3));
+        assert_eq!(rpsls.score_as_outcomes("D Z\n"), Ok(5 + 6));
+        assert_eq!(
+            rpsls.score_as_outcomes("A Z\nD W\n"),
+            Err(RulesError::UnknownLetter { line: 2, letter: 'W' })
+        );
+        assert_eq!(rpsls.score_as_shapes("AX\n"), Err(RulesError::BadLine { line: 1 }));
+    }
+
+    #[test]
+    fn it_refuses_bad_rulesets() {
+        assert_eq!(
+            Ruleset::try_from("Rock:A:X:1,Paper:B:Y:2"),
+            Err(RulesError::EvenShapes { shapes: 2 })
+        );
+        assert_eq!(
+            Ruleset::try_from("Rock:A:X:1,Paper:B:Y,Scissors:C:Z:3"),
+            Err(RulesError::BadShape { shape: "Paper:B:Y".to_string() })
+        );
+        assert_eq!(
+            Ruleset::try_from("Rock:A:X:one"),
+            Err(RulesError::BadShape { shape: "Rock:A:X:one".to_string() })
+        );
+        assert_eq!(
+            Ruleset::try_from("Rock:A:X:1,Paper:A:Y:2,Scissors:C:Z:3"),
+            Err(RulesError::SharedLetter { first: "Rock".to_string(), second: "Paper".to_string() })
+        );
+        let args = |values: &[&str]| -> Vec<String> { values.iter().map(|x| x.to_string()).collect() };
+        assert_eq!(rules_from_args(&args(&["day", "--rules", "rpsls"])), Ok(Some(Ruleset::rpsls())));
+        assert_eq!(rules_from_args(&args(&["day"])), Ok(None));
+        assert!(rules_from_args(&args(&["day", "--rules", "Rock:A:X"])).is_err());
+        assert!(rules_from_args(&args(&["day", "--rules"])).is_err());
+    }
+}
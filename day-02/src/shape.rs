@@ -0,0 +1,135 @@
+use serde::Serialize;
+
+/// A hand of the game, each one beats the next one in the cycle Rock, Scissors, Paper
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// Result of a round for me
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+// the opponent column
+impl From<char> for Shape {
+    fn from(item: char) -> Self {
+        match item {
+            'A' => Shape::Rock,
+            'B' => Shape::Paper,
+            'C' => Shape::Scissors,
+            _ => panic!("Unknown shape: {:?}", item),
+        }
+    }
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// The shape this one wins against
+    pub fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape this one loses against
+    pub fn loses_to(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        }
+    }
+
+    /// Points for playing it
+    pub fn score(self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    /// What happens to me playing this shape against `opponent`
+    pub fn outcome_against(self, opponent: Shape) -> Outcome {
+        if self == opponent {
+            Outcome::Draw
+        } else if self.beats() == opponent {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// The shape to play against `opponent` to get `outcome`
+    pub fn for_outcome(opponent: Shape, outcome: Outcome) -> Shape {
+        match outcome {
+            Outcome::Lose => opponent.beats(),
+            Outcome::Draw => opponent,
+            Outcome::Win => opponent.loses_to(),
+        }
+    }
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    /// Points for the result of the round
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// Score of a round: my shape plus the outcome
+pub fn round_score(opponent: Shape, me: Shape) -> u32 {
+    me.score() + me.outcome_against(opponent).score()
+}
+
+#[cfg(test)]
+mod shape_test {
+    use super::*;
+
+    #[test]
+    fn it_can_relate_shapes() {
+        for shape in Shape::ALL {
+            assert_eq!(shape.beats().loses_to(), shape);
+            assert_eq!(shape.loses_to().beats(), shape);
+            assert_ne!(shape.beats(), shape.loses_to());
+            assert_eq!(shape.outcome_against(shape), Outcome::Draw);
+            assert_eq!(shape.outcome_against(shape.beats()), Outcome::Win);
+            assert_eq!(shape.outcome_against(shape.loses_to()), Outcome::Lose);
+        }
+        assert_eq!(Shape::Rock.beats(), Shape::Scissors);
+        assert_eq!(Shape::Rock.loses_to(), Shape::Paper);
+    }
+
+    #[test]
+    fn it_can_find_shape_for_outcome() {
+        for opponent in Shape::ALL {
+            for outcome in Outcome::ALL {
+                let me = Shape::for_outcome(opponent, outcome);
+                assert_eq!(me.outcome_against(opponent), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn it_can_parse_and_score() {
+        assert_eq!(Shape::from('B'), Shape::Paper);
+        assert_eq!(round_score(Shape::Rock, Shape::Paper), 8);
+        assert_eq!(round_score(Shape::Paper, Shape::Rock), 1);
+        assert_eq!(round_score(Shape::Scissors, Shape::Scissors), 6);
+    }
+}
@@ -0,0 +1,407 @@
+use crate::explorer::Interpretation;
+use crate::shape::{round_score, Outcome, Shape};
+use crate::{Round, SecondColumn};
+
+fn shape_index(shape: Shape) -> usize {
+    match shape {
+        Shape::Rock => 0,
+        Shape::Paper => 1,
+        Shape::Scissors => 2,
+    }
+}
+
+fn column_index(column: SecondColumn) -> usize {
+    match column {
+        SecondColumn::X => 0,
+        SecondColumn::Y => 1,
+        SecondColumn::Z => 2,
+    }
+}
+
+const COLUMNS: [SecondColumn; 3] = [SecondColumn::X, SecondColumn::Y, SecondColumn::Z];
+
+/// How often the opponent plays each shape, Rock, Paper and Scissors in that order
+pub type Distribution = [f64; 3];
+
+fn normalize(counts: &[u32; 3]) -> Option<Distribution> {
+    let total: u32 = counts.iter().sum();
+    if total == 0 {
+        return None;
+    }
+    Some(counts.map(|count| count as f64 / total as f64))
+}
+
+/// Average score of playing `me` against an opponent drawn from `distribution`
+pub fn expected_round_score(distribution: &Distribution, me: Shape) -> f64 {
+    Shape::ALL
+        .iter()
+        .map(|opponent| distribution[shape_index(*opponent)] * round_score(*opponent, me) as f64)
+        .sum()
+}
+
+/// The shape with the best expected score, the cheapest one on a tie
+pub fn best_response(distribution: &Distribution) -> Shape {
+    let mut best = Shape::Rock;
+    for shape in Shape::ALL {
+        if expected_round_score(distribution, shape) > expected_round_score(distribution, best) {
+            best = shape;
+        }
+    }
+    best
+}
+
+/// What the opponent column of the guide tells about the opponent
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct OpponentStats {
+    // rounds per opponent shape
+    counts: [u32; 3],
+    // [previous][next] opponent shape, for consecutive rounds
+    transitions: [[u32; 3]; 3],
+    // [opponent shape][second column], to replay the guide in simulations
+    columns: [[u32; 3]; 3],
+}
+
+impl From<&[Round]> for OpponentStats {
+    fn from(item: &[Round]) -> Self {
+        let mut stats = OpponentStats::default();
+        for (i, round) in item.iter().enumerate() {
+            let opponent = shape_index(round.opponent);
+            stats.counts[opponent] += 1;
+            stats.columns[opponent][column_index(round.column)] += 1;
+            if i > 0 {
+                stats.transitions[shape_index(item[i - 1].opponent)][opponent] += 1;
+            }
+        }
+        stats
+    }
+}
+
+impl OpponentStats {
+    pub fn rounds(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Share of each shape in the guide, `None` for an empty guide
+    pub fn distribution(&self) -> Option<Distribution> {
+        normalize(&self.counts)
+    }
+
+    /// Share of each shape right after the opponent played `previous`,
+    /// `None` if it never did before the last round
+    pub fn distribution_after(&self, previous: Shape) -> Option<Distribution> {
+        normalize(&self.transitions[shape_index(previous)])
+    }
+
+    /// The fixed and the conditioned best responses, `None` for an empty guide
+    pub fn best_response(&self) -> Option<BestResponse> {
+        let fixed = best_response(&self.distribution()?);
+        let after = Shape::ALL.map(|previous| {
+            self.distribution_after(previous)
+                .map_or(fixed, |distribution| best_response(&distribution))
+        });
+        Some(BestResponse { fixed, after })
+    }
+
+    fn draw(counts: &[u32; 3], rng: &mut fastrand::Rng) -> Option<usize> {
+        let total: u32 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let mut ticket = rng.u32(0..total);
+        for (i, count) in counts.iter().enumerate() {
+            if ticket < *count {
+                return Some(i);
+            }
+            ticket -= count;
+        }
+        unreachable!()
+    }
+
+    /// A guide of `length` rounds following the statistics: each opponent shape is drawn
+    /// knowing the previous one, then its second column knowing the shape.
+    /// Panics on empty stats
+    pub fn sample(&self, length: usize, rng: &mut fastrand::Rng) -> Vec<Round> {
+        let mut guide: Vec<Round> = Vec::with_capacity(length);
+        for _ in 0..length {
+            let opponent = guide
+                .last()
+                .and_then(|previous| {
+                    OpponentStats::draw(&self.transitions[shape_index(previous.opponent)], rng)
+                })
+                .or_else(|| OpponentStats::draw(&self.counts, rng))
+                .expect("no round to learn from");
+            let column = OpponentStats::draw(&self.columns[opponent], rng).unwrap();
+            guide.push(Round {
+                opponent: Shape::ALL[opponent],
+                column: COLUMNS[column],
+            });
+        }
+        guide
+    }
+}
+
+/// Score-maximizing shapes against the opponent of a guide
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BestResponse {
+    // whatever happened before
+    pub fixed: Shape,
+    // knowing the previous opponent shape, Rock, Paper and Scissors in that order
+    pub after: [Shape; 3],
+}
+
+/// A way to pick my shape each round
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Strategy {
+    // play what the guide says, read one way or another
+    Guide(Interpretation),
+    Fixed(Shape),
+    // `after` of a best response, `fixed` for the first round
+    Conditioned(BestResponse),
+    Random,
+}
+
+impl Strategy {
+    pub fn label(&self) -> String {
+        match self {
+            Strategy::Guide(interpretation) => format!("guide {}", interpretation.label()),
+            Strategy::Fixed(shape) => format!("always {:?}", shape),
+            Strategy::Conditioned(_) => "best response to previous".to_string(),
+            Strategy::Random => "random".to_string(),
+        }
+    }
+
+    /// My shape for `round`, only a guide strategy looks at its second column
+    pub fn play(&self, previous: Option<Shape>, round: &Round, rng: &mut fastrand::Rng) -> Shape {
+        match self {
+            Strategy::Guide(Interpretation::Shapes(shapes)) => shapes[column_index(round.column)],
+            Strategy::Guide(Interpretation::Outcomes(outcomes)) => {
+                Shape::for_outcome(round.opponent, outcomes[column_index(round.column)])
+            }
+            Strategy::Fixed(shape) => *shape,
+            Strategy::Conditioned(response) => {
+                previous.map_or(response.fixed, |shape| response.after[shape_index(shape)])
+            }
+            Strategy::Random => Shape::ALL[rng.usize(0..3)],
+        }
+    }
+
+    /// Total over a whole guide
+    pub fn score(&self, guide: &[Round], rng: &mut fastrand::Rng) -> u32 {
+        let mut previous = None;
+        let mut total = 0;
+        for round in guide {
+            total += round_score(round.opponent, self.play(previous, round, rng));
+            previous = Some(round.opponent);
+        }
+        total
+    }
+}
+
+/// The guide as the elves meant it, the best responses, and random play
+pub fn default_strategies(stats: &OpponentStats) -> Vec<Strategy> {
+    let mut strategies = vec![Strategy::Guide(Interpretation::Outcomes(Outcome::ALL))];
+    if let Some(response) = stats.best_response() {
+        strategies.push(Strategy::Fixed(response.fixed));
+        strategies.push(Strategy::Conditioned(response));
+    }
+    strategies.push(Strategy::Random);
+    strategies
+}
+
+/// How a strategy did over every match of a tournament
+#[derive(Debug, PartialEq, Clone)]
+pub struct Standing {
+    pub strategy: Strategy,
+    pub mean: f64,
+    // population variance of the match totals
+    pub variance: f64,
+    // matches where no strategy did better
+    pub wins: usize,
+}
+
+/// Plays `matches` matches as long as the guide, each against a guide sampled from
+/// its statistics, every strategy facing the same rounds. The same seed gives the same standings
+pub fn tournament(
+    guide: &[Round],
+    strategies: &[Strategy],
+    matches: usize,
+    seed: u64,
+) -> Vec<Standing> {
+    let stats = OpponentStats::from(guide);
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let mut totals = vec![Vec::with_capacity(matches); strategies.len()];
+    for _ in 0..matches {
+        let sampled = stats.sample(guide.len(), &mut rng);
+        for (i, strategy) in strategies.iter().enumerate() {
+            totals[i].push(strategy.score(&sampled, &mut rng));
+        }
+    }
+    strategies
+        .iter()
+        .enumerate()
+        .map(|(i, strategy)| {
+            let n = matches.max(1) as f64;
+            let mean = totals[i].iter().map(|x| *x as f64).sum::<f64>() / n;
+            let variance = totals[i]
+                .iter()
+                .map(|x| (*x as f64 - mean).powi(2))
+                .sum::<f64>()
+                / n;
+            let wins = (0..matches)
+                .filter(|m| totals.iter().all(|other| other[*m] <= totals[i][*m]))
+                .count();
+            Standing {
+                strategy: *strategy,
+                mean,
+                variance,
+                wins,
+            }
+        })
+        .collect()
+}
+
+/// Opponent distribution, best responses, then one line per standing
+pub fn format_report(stats: &OpponentStats, standings: &[Standing]) -> String {
+    let mut report = String::new();
+    if let Some(distribution) = stats.distribution() {
+        report.push_str(&format!(
+            "Opponent over {} rounds: Rock {:.3} Paper {:.3} Scissors {:.3}\n",
+            stats.rounds(),
+            distribution[0],
+            distribution[1],
+            distribution[2]
+        ));
+    }
+    if let Some(response) = stats.best_response() {
+        report.push_str(&format!("Best response: {:?}\n", response.fixed));
+        for previous in Shape::ALL {
+            report.push_str(&format!(
+                "  after {:?}: {:?}\n",
+                previous,
+                response.after[shape_index(previous)]
+            ));
+        }
+    }
+    for standing in standings {
+        report.push_str(&format!(
+            "{:<40} mean {:>10.1} variance {:>12.1} wins {:>6}\n",
+            standing.strategy.label(),
+            standing.mean,
+            standing.variance,
+            standing.wins
+        ));
+    }
+    report
+}
+
+// the number following `flag`
+fn flag_number<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or(format!("{} expects a number", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number, got {:?}", flag, value))
+}
+
+/// `--tournament` on the command line, with `--matches N` (100 by default)
+/// and `--seed S` (0 by default), gives `(matches, seed)`,
+/// an error if N or S is missing or not a number
+pub fn tournament_from_args(args: &[String]) -> Result<Option<(usize, u64)>, String> {
+    if !args.iter().any(|arg| arg == "--tournament") {
+        return Ok(None);
+    }
+    let (mut matches, mut seed) = (100, 0);
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--matches" => matches = flag_number(arg, iter.next())?,
+            "--seed" => seed = flag_number(arg, iter.next())?,
+            _ => {}
+        }
+    }
+    Ok(Some((matches, seed)))
+}
+
+#[cfg(test)]
+mod tournament_test {
+    use super::*;
+    use crate::{parse_input, solve_two};
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn it_can_find_best_responses() {
+        let stats = OpponentStats::from(parse_input(EXAMPLE.to_string()).as_slice());
+        let third = 1.0 / 3.0;
+        assert_eq!(stats.distribution(), Some([third, third, third]));
+        // every shape wins as often as it loses, Scissors is worth the most
+        assert_eq!(
+            expected_round_score(&[third, third, third], Shape::Scissors),
+            6.0
+        );
+        // Rock then Paper then Scissors
+        let response = stats.best_response().unwrap();
+        assert_eq!(response.fixed, Shape::Scissors);
+        assert_eq!(response.after, [Shape::Scissors, Shape::Rock, Shape::Scissors]);
+        assert_eq!(best_response(&[0.0, 0.0, 1.0]), Shape::Rock);
+        assert_eq!(OpponentStats::default().best_response(), None);
+    }
+
+    #[test]
+    fn it_can_score_strategies_on_the_guide() {
+        let guide = parse_input(EXAMPLE.to_string());
+        let mut rng = fastrand::Rng::with_seed(0);
+        let elves = Strategy::Guide(Interpretation::Outcomes(Outcome::ALL));
+        assert_eq!(elves.score(&guide, &mut rng), solve_two(guide.clone()));
+        assert_eq!(Strategy::Fixed(Shape::Rock).score(&guide, &mut rng), 4 + 1 + 7);
+        let stats = OpponentStats::from(guide.as_slice());
+        let conditioned = Strategy::Conditioned(stats.best_response().unwrap());
+        // Scissors first and loses, then answers the Paper and Scissors that follow
+        assert_eq!(conditioned.score(&guide, &mut rng), 3 + 9 + 7);
+    }
+
+    #[test]
+    fn it_can_run_a_seeded_tournament() {
+        let mut guide = String::new();
+        for i in 0..60 {
+            guide.push_str(["A Y\n", "A Z\n", "B X\n", "A X\n", "C Y\n"][i % 5]);
+        }
+        let guide = parse_input(guide);
+        let stats = OpponentStats::from(guide.as_slice());
+        let strategies = default_strategies(&stats);
+        assert_eq!(strategies.len(), 4);
+        assert_eq!(strategies[1], Strategy::Fixed(Shape::Paper));
+
+        let standings = tournament(&guide, &strategies, 200, 42);
+        assert_eq!(standings, tournament(&guide, &strategies, 200, 42));
+        assert_ne!(standings, tournament(&guide, &strategies, 200, 43));
+        // the opponent mostly plays Rock, answering Paper beats playing at random
+        assert!(standings[1].mean > standings[3].mean);
+        assert!(standings.iter().all(|s| s.variance >= 0.0));
+        assert!(standings.iter().map(|s| s.wins).sum::<usize>() >= 200);
+        // a sampled guide follows the statistics of the original one
+        let sampled = stats.sample(60, &mut fastrand::Rng::with_seed(7));
+        assert!(sampled.iter().all(|round| guide.contains(round)));
+    }
+
+    #[test]
+    fn it_can_read_flags() {
+        let args = |values: &[&str]| {
+            values
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(tournament_from_args(&args(&["day", "--stdin"])), Ok(None));
+        assert_eq!(tournament_from_args(&args(&["day", "--tournament"])), Ok(Some((100, 0))));
+        assert_eq!(
+            tournament_from_args(&args(&["day", "--tournament", "--seed", "7", "--matches", "10"])),
+            Ok(Some((10, 7)))
+        );
+        assert_eq!(
+            tournament_from_args(&args(&["day", "--tournament", "--matches", "x"])),
+            Err("--matches expects a number, got \"x\"".to_string())
+        );
+        assert!(tournament_from_args(&args(&["day", "--tournament", "--seed", "-1"])).is_err());
+        assert!(tournament_from_args(&args(&["day", "--tournament", "--seed"])).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{get_letter_priority, AdventResponse, ItemSet, RuckSack};
+
+/// What to do with a rucksack or a group that does not share exactly one item
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Strictness {
+    // no answer while something is wrong
+    Strict,
+    // take the first shared item by priority, nothing if there is none, and report it
+    Lenient,
+}
+
+/// Something the puzzle says cannot happen, line numbers start at 1
+#[derive(Debug, PartialEq, Clone)]
+pub enum Anomaly {
+    // compartments sharing zero or several items
+    Compartments { line: usize, shared: Vec<char> },
+    // rucksacks of a group sharing zero or several items
+    Group { lines: Vec<usize>, shared: Vec<char> },
+    // rucksacks left over after the last full group
+    PartialGroup { lines: Vec<usize>, group_size: usize },
+}
+
+fn describe_items(shared: &[char]) -> String {
+    if shared.is_empty() {
+        "no item".to_string()
+    } else {
+        shared.iter().map(|c| c.to_string()).collect::<Vec<String>>().join(", ")
+    }
+}
+
+fn describe_lines(lines: &[usize]) -> String {
+    lines.iter().map(|l| l.to_string()).collect::<Vec<String>>().join(", ")
+}
+
+impl Anomaly {
+    /// e.g. "line 3: compartments share a, b"
+    pub fn describe(&self) -> String {
+        match self {
+            Anomaly::Compartments { line, shared } => {
+                format!("line {}: compartments share {}", line, describe_items(shared))
+            }
+            Anomaly::Group { lines, shared } => {
+                format!("lines {}: group shares {}", describe_lines(lines), describe_items(shared))
+            }
+            Anomaly::PartialGroup { lines, group_size } => format!(
+                "lines {}: only {} of a group of {}",
+                describe_lines(lines),
+                lines.len(),
+                group_size
+            ),
+        }
+    }
+}
+
+/// Answers of both parts with what was wrong on the way
+#[derive(Debug, PartialEq, Clone)]
+pub struct Checked {
+    pub first: AdventResponse,
+    pub second: AdventResponse,
+    pub anomalies: Vec<Anomaly>,
+}
+
+// priority of the first shared item, 0 when there is none
+fn first_priority(shared: &[char]) -> AdventResponse {
+    shared.first().map_or(0, |item| get_letter_priority(*item))
+}
+
+/// Checks every rucksack and every group of `group_size` from the raw input,
+/// so line numbers account for empty lines
+pub fn diagnose(puzzle_input: &str, group_size: usize) -> Checked {
+    assert!(group_size > 0, "a group needs at least one rucksack");
+    let rucksacks: Vec<(usize, RuckSack)> = puzzle_input
+        .split('\n')
+        .enumerate()
+        .filter(|(_, row)| !row.is_empty())
+        .map(|(i, row)| (i + 1, RuckSack::from(row)))
+        .collect();
+    let mut checked = Checked {
+        first: 0,
+        second: 0,
+        anomalies: vec![],
+    };
+    for (line, rucksack) in rucksacks.iter() {
+        let shared = (rucksack.left_items() & rucksack.right_items()).items();
+        checked.first += first_priority(&shared);
+        if shared.len() != 1 {
+            checked.anomalies.push(Anomaly::Compartments {
+                line: *line,
+                shared,
+            });
+        }
+    }
+    for group in rucksacks.chunks(group_size) {
+        let lines: Vec<usize> = group.iter().map(|(line, _)| *line).collect();
+        if group.len() < group_size {
+            checked.anomalies.push(Anomaly::PartialGroup { lines, group_size });
+            continue;
+        }
+        let shared = ItemSet::intersect_all(group.iter().map(|(_, r)| r.items())).items();
+        checked.second += first_priority(&shared);
+        if shared.len() != 1 {
+            checked.anomalies.push(Anomaly::Group { lines, shared });
+        }
+    }
+    checked
+}
+
+/// Both answers, or every anomaly if there is one and `strictness` is `Strict`
+pub fn solve_checked(
+    puzzle_input: &str,
+    group_size: usize,
+    strictness: Strictness,
+) -> Result<Checked, Vec<Anomaly>> {
+    let checked = diagnose(puzzle_input, group_size);
+    if strictness == Strictness::Strict && !checked.anomalies.is_empty() {
+        return Err(checked.anomalies);
+    }
+    Ok(checked)
+}
+
+/// `--check` (lenient) or `--strict` on the command line
+pub fn strictness_from_args(args: &[String]) -> Option<Strictness> {
+    if args.iter().any(|arg| arg == "--strict") {
+        Some(Strictness::Strict)
+    } else if args.iter().any(|arg| arg == "--check") {
+        Some(Strictness::Lenient)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod diagnostics_test {
+    use super::*;
+    use crate::{parse_input, solve_one, solve_two};
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn it_finds_nothing_wrong_with_the_example() {
+        let checked = solve_checked(EXAMPLE, 3, Strictness::Strict).unwrap();
+        assert!(checked.anomalies.is_empty());
+        assert_eq!(checked.first, solve_one(parse_input(EXAMPLE.to_string())));
+        assert_eq!(checked.second, solve_two(parse_input(EXAMPLE.to_string())));
+    }
+
+    #[test]
+    fn it_reports_lines_and_items() {
+        // line 2 shares nothing, line 4 shares a and b, line 3 is empty
+        let input = "abca\nabcd\n\nabab\nxyzx\n";
+        let checked = diagnose(input, 2);
+        assert_eq!(
+            checked.anomalies,
+            vec![
+                Anomaly::Compartments {
+                    line: 2,
+                    shared: vec![]
+                },
+                Anomaly::Compartments {
+                    line: 4,
+                    shared: vec!['a', 'b']
+                },
+                Anomaly::Group {
+                    lines: vec![1, 2],
+                    shared: vec!['a', 'b', 'c']
+                },
+                Anomaly::Group {
+                    lines: vec![4, 5],
+                    shared: vec![]
+                },
+            ]
+        );
+        // a, nothing, a, x then a for the groups
+        assert_eq!(checked.first, 1 + 1 + 24);
+        assert_eq!(checked.second, 1);
+        assert_eq!(
+            checked.anomalies[1].describe(),
+            "line 4: compartments share a, b"
+        );
+        assert_eq!(checked.anomalies[3].describe(), "lines 4, 5: group shares no item");
+    }
+
+    #[test]
+    fn it_fails_or_continues_by_strictness() {
+        let input = "abca\nxyzx\nqrsq\nabab\n";
+        let anomalies = solve_checked(input, 3, Strictness::Strict).unwrap_err();
+        assert_eq!(
+            anomalies.last(),
+            Some(&Anomaly::PartialGroup {
+                lines: vec![4],
+                group_size: 3
+            })
+        );
+        assert_eq!(anomalies.last().unwrap().describe(), "lines 4: only 1 of a group of 3");
+        let checked = solve_checked(input, 3, Strictness::Lenient).unwrap();
+        assert_eq!(checked.anomalies, anomalies);
+        assert_eq!(checked.first, 1 + 24 + 17 + 1);
+
+        let args = |values: &[&str]| values.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(strictness_from_args(&args(&["day", "--check"])), Some(Strictness::Lenient));
+        assert_eq!(
+            strictness_from_args(&args(&["day", "--check", "--strict"])),
+            Some(Strictness::Strict)
+        );
+        assert_eq!(strictness_from_args(&args(&["day"])), None);
+    }
+}
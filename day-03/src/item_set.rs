@@ -0,0 +1,220 @@
+use crate::{get_letter_priority, RuckSack};
+use std::ops::BitAnd;
+
+/// A set of item types as a 52-bit mask, bit `priority - 1` for each letter,
+/// so intersecting compartments or rucksacks is a single AND
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub struct ItemSet(u64);
+
+impl From<&[char]> for ItemSet {
+    fn from(item: &[char]) -> Self {
+        let mut set = ItemSet::default();
+        for letter in item {
+            set.insert(*letter);
+        }
+        set
+    }
+}
+
+impl From<&str> for ItemSet {
+    fn from(item: &str) -> Self {
+        let letters: Vec<char> = item.chars().collect();
+        ItemSet::from(letters.as_slice())
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+
+// bit of a letter, None for anything but a-z and A-Z
+fn bit(letter: char) -> Option<u64> {
+    if !letter.is_ascii_alphabetic() {
+        return None;
+    }
+    Some(1 << (get_letter_priority(letter) - 1))
+}
+
+// letter of bit `index`, priority - 1
+fn letter_at(index: u32) -> char {
+    let index = index as u8;
+    if index < 26 {
+        (b'a' + index) as char
+    } else {
+        (b'A' + index - 26) as char
+    }
+}
+
+impl ItemSet {
+    /// Every item type, the start of an intersection
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    /// Adds a letter, false for any other item which the set cannot hold,
+    /// see [`PriorityTable`](crate::PriorityTable) for those
+    pub fn insert(&mut self, letter: char) -> bool {
+        match bit(letter) {
+            Some(bit) => {
+                self.0 |= bit;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, letter: char) -> bool {
+        bit(letter).is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Items of the set, by priority
+    pub fn items(&self) -> Vec<char> {
+        ('a'..='z')
+            .chain('A'..='Z')
+            .filter(|letter| self.contains(*letter))
+            .collect()
+    }
+
+    /// The item of lowest priority, None for an empty set
+    pub fn first(&self) -> Option<char> {
+        if self.is_empty() {
+            return None;
+        }
+        // priority - 1 of the item is the number of zeros below its bit
+        Some(letter_at(self.0.trailing_zeros()))
+    }
+
+    /// The item if there is exactly one
+    pub fn single(&self) -> Option<char> {
+        if self.len() != 1 {
+            return None;
+        }
+        self.first()
+    }
+
+    /// Items found in every set, all the items for no set
+    pub fn intersect_all<I: IntoIterator<Item = ItemSet>>(sets: I) -> ItemSet {
+        sets.into_iter().fold(ItemSet::ALL, |common, set| common & set)
+    }
+}
+
+/// Why rucksacks cannot be split in groups
+#[derive(Debug, PartialEq)]
+pub enum GroupError {
+    ZeroSize,
+    // the last group would only have `rucksacks` of `group_size`
+    PartialGroup { rucksacks: usize, group_size: usize },
+    // the rucksacks of group `group`, from 0, share no item
+    NoCommonItem { group: usize },
+}
+
+// `rucksacks` split in whole groups of `group_size`
+pub(crate) fn check_group_size(rucksacks: usize, group_size: usize) -> Result<(), GroupError> {
+    if group_size == 0 {
+        return Err(GroupError::ZeroSize);
+    }
+    let leftover = rucksacks % group_size;
+    if leftover != 0 {
+        return Err(GroupError::PartialGroup {
+            rucksacks: leftover,
+            group_size,
+        });
+    }
+    Ok(())
+}
+
+/// Items shared by each group of `group_size` consecutive rucksacks, from the first one.
+/// The rucksacks must split in whole groups
+pub fn group_badges(
+    rucksacks: &[RuckSack],
+    group_size: usize,
+) -> Result<Vec<ItemSet>, GroupError> {
+    check_group_size(rucksacks.len(), group_size)?;
+    Ok(rucksacks
+        .chunks(group_size)
+        .map(|group| ItemSet::intersect_all(group.iter().map(|r| r.items())))
+        .collect())
+}
+
+#[cfg(test)]
+mod item_set_test {
+    use super::*;
+    use crate::parse_input;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn it_can_build_and_intersect() {
+        let left = ItemSet::from("vJrwpWtwJgWr");
+        let right = ItemSet::from("hcsFMMfFFhFp");
+        assert_eq!(left.len(), 8);
+        assert!(left.contains('J') && !left.contains('j') && !left.contains('1'));
+        assert_eq!((left & right).single(), Some('p'));
+        assert_eq!((left & right).items(), vec!['p']);
+        assert_eq!(ItemSet::from("aZz").items(), vec!['a', 'z', 'Z']);
+        assert_eq!(ItemSet::from("Z").single(), Some('Z'));
+        assert_eq!(ItemSet::from("ab").single(), None);
+        assert_eq!(ItemSet::ALL.items().len(), 52);
+        assert_eq!(ItemSet::intersect_all(vec![]), ItemSet::ALL);
+        assert_eq!(ItemSet::from("zQb").first(), Some('b'));
+        assert_eq!(ItemSet::default().first(), None);
+    }
+
+    #[test]
+    fn it_leaves_out_other_items() {
+        let mut set = ItemSet::default();
+        assert!(set.insert('a'));
+        assert!(!set.insert('é') && !set.insert('1'));
+        assert!(!set.contains('é'));
+        assert_eq!(ItemSet::from("aé€b").items(), vec!['a', 'b']);
+        assert_eq!(parse_input("aébé\n".to_string())[0].items().len(), 2);
+    }
+
+    #[test]
+    fn it_can_find_badges_for_any_group_size() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let badges = group_badges(&parsed, 3).unwrap();
+        let singles: Vec<Option<char>> = badges.iter().map(|b| b.single()).collect();
+        assert_eq!(singles, vec![Some('r'), Some('Z')]);
+        // every rucksack of the example has more than one item
+        assert!(group_badges(&parsed, 1).unwrap().iter().all(|b| b.len() > 1));
+        assert_eq!(group_badges(&parsed, 6).unwrap()[0], ItemSet::default());
+        assert_eq!(group_badges(&parsed, 2).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn it_refuses_partial_groups() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        assert_eq!(
+            group_badges(&parsed, 4),
+            Err(GroupError::PartialGroup {
+                rucksacks: 2,
+                group_size: 4
+            })
+        );
+        assert_eq!(
+            group_badges(&parsed[..5], 3),
+            Err(GroupError::PartialGroup {
+                rucksacks: 2,
+                group_size: 3
+            })
+        );
+        assert_eq!(group_badges(&parsed, 0), Err(GroupError::ZeroSize));
+    }
+}
@@ -0,0 +1,380 @@
+//! Day 3: Rucksack Reorganization, items misplaced between compartments.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub mod diagnostics;
+pub mod item_set;
+pub mod priority;
+pub mod repack;
+pub use advent_common::trace;
+pub use diagnostics::{Anomaly, Strictness};
+pub use advent_common::InputSource;
+pub use item_set::{group_badges, GroupError, ItemSet};
+pub use priority::{PriorityError, PriorityTable};
+pub use repack::{plan_input, plan_repack, RepackError, RepackPlan};
+
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 3;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    // item of both compartments (part 1) or badge of a group (part 2)
+    SharedItem { part: u8, index: usize, item: char, priority: u32 },
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct RuckSack {
+    left: Vec<char>,
+    right: Vec<char>,
+}
+impl RuckSack {
+    pub fn left_items(&self) -> ItemSet {
+        ItemSet::from(self.left.as_slice())
+    }
+
+    pub fn right_items(&self) -> ItemSet {
+        ItemSet::from(self.right.as_slice())
+    }
+
+    /// Items of both compartments
+    pub fn items(&self) -> ItemSet {
+        let mut items = self.left_items();
+        for letter in self.right.iter() {
+            items.insert(*letter);
+        }
+        items
+    }
+}
+
+pub type AdventParsed = Vec<RuckSack>;
+pub type AdventResponse = u32;
+
+// a line of the input, split into 2 compartments of as many characters
+impl From<&str> for RuckSack {
+    fn from(item: &str) -> Self {
+        let mut left: Vec<char> = item.chars().collect();
+        let right = left.split_off(left.len() / 2);
+        RuckSack { left, right }
+    }
+}
+
+/// Splits each line into its two compartments
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut result = vec![];
+    // go over each line
+    for row in puzzle_input.split('\n') {
+        if row.is_empty() {
+            continue;
+        }
+        result.push(RuckSack::from(row))
+    }
+    result
+}
+
+/// Priority of the puzzle, panics for anything but a-z and A-Z,
+/// see [`PriorityTable`] for other items
+pub fn get_letter_priority(letter: char) -> AdventResponse {
+    match letter {
+        // Lowercase: ascii - 96
+        'a'..='z' => (letter as u32) - 96,
+        // Uppercase: ascii - 64 + 26
+        'A'..='Z' => (letter as u32) - 64 + 26,
+        _ => panic!("No priority for item {:?}", letter),
+    }
+}
+
+/// Items found in every list, by `priority` then character
+pub fn shared_items<P: Fn(char) -> u32>(lists: &[&[char]], priority: P) -> Vec<char> {
+    let mut sets = lists.iter().map(|list| list.iter().copied().collect::<BTreeSet<char>>());
+    let Some(first) = sets.next() else {
+        return vec![];
+    };
+    let common = sets.fold(first, |common, set| &common & &set);
+    let mut shared: Vec<char> = common.into_iter().collect();
+    shared.sort_by_key(|item| (priority(*item), *item));
+    shared
+}
+
+/// Sum of the priorities of the item in both compartments, whatever gives the priorities,
+/// a rucksack without one counts for 0 as in the `diagnostics` module.
+/// Slower than `solve_one`, for the items of a [`PriorityTable`]
+pub fn solve_one_by<P: Fn(char) -> u32>(parsed: &[RuckSack], priority: P) -> AdventResponse {
+    let mut result = 0;
+    for (index, rucksack) in parsed.iter().enumerate() {
+        let shared = shared_items(&[&rucksack.left, &rucksack.right], &priority);
+        let Some(duplicated) = shared.first().copied() else {
+            continue;
+        };
+        let dup_value = priority(duplicated);
+        trace::emit(TraceEvent::SharedItem { part: 1, index, item: duplicated, priority: dup_value });
+        result += dup_value;
+    }
+    result
+}
+
+/// Sum of the priorities of the badge of each group of `group_size` rucksacks,
+/// an error if the last group is not full or a group has no badge
+pub fn solve_groups_by<P: Fn(char) -> u32>(
+    parsed: &[RuckSack],
+    group_size: usize,
+    priority: P,
+) -> Result<AdventResponse, GroupError> {
+    item_set::check_group_size(parsed.len(), group_size)?;
+    let mut result = 0;
+    for (index, group) in parsed.chunks(group_size).enumerate() {
+        let items: Vec<Vec<char>> = group
+            .iter()
+            .map(|r| [r.left.as_slice(), r.right.as_slice()].concat())
+            .collect();
+        let lists: Vec<&[char]> = items.iter().map(|i| i.as_slice()).collect();
+        let Some(common_letter) = shared_items(&lists, &priority).first().copied() else {
+            return Err(GroupError::NoCommonItem { group: index });
+        };
+        let common_value = priority(common_letter);
+        trace::emit(TraceEvent::SharedItem { part: 2, index, item: common_letter, priority: common_value });
+        result += common_value;
+    }
+    Ok(result)
+}
+
+/// Sum of the priorities of the item in both compartments,
+/// intersecting the compartments as [`ItemSet`]s
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    let mut result = 0;
+    for (index, rucksack) in parsed.iter().enumerate() {
+        let Some(duplicated) = (rucksack.left_items() & rucksack.right_items()).first() else {
+            continue;
+        };
+        let dup_value = get_letter_priority(duplicated);
+        trace::emit(TraceEvent::SharedItem { part: 1, index, item: duplicated, priority: dup_value });
+        result += dup_value;
+    }
+    result
+}
+
+/// Sum of the priorities of the badge of each group of three,
+/// panics when they do not split in such groups, see `solve_groups`
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    solve_groups(&parsed, 3).unwrap()
+}
+
+/// Same as `solve_groups_by` with the puzzle priorities, intersecting the rucksacks as [`ItemSet`]s
+pub fn solve_groups(parsed: &[RuckSack], group_size: usize) -> Result<AdventResponse, GroupError> {
+    let mut result = 0;
+    for (index, badges) in group_badges(parsed, group_size)?.iter().enumerate() {
+        let Some(common_letter) = badges.first() else {
+            return Err(GroupError::NoCommonItem { group: index });
+        };
+        let common_value = get_letter_priority(common_letter);
+        trace::emit(TraceEvent::SharedItem { part: 2, index, item: common_letter, priority: common_value });
+        result += common_value;
+    }
+    Ok(result)
+}
+
+/// `--group-size N` on the command line, an error if N is missing or not a number
+pub fn group_size_from_args(args: &[String]) -> Result<Option<usize>, String> {
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--group-size" {
+            return match iter.next() {
+                Some(size) => size
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| format!("--group-size expects a number, got {:?}", size)),
+                None => Err("--group-size expects a number".to_string()),
+            };
+        }
+    }
+    Ok(None)
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts,
+/// `--group-size N` finds the badges of groups of N for the second one,
+/// `--check` and `--strict` report the rucksacks and groups not sharing exactly one item,
+/// `--repack` plans how to fix each rucksack and even out each group,
+/// `--priorities PATH` solves with the priority table of a file instead
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let args: Vec<String> = std::env::args().collect();
+    let group_size = match group_size_from_args(&args) {
+        Ok(group_size) => group_size,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+    if let Some(strictness) = diagnostics::strictness_from_args(&args) {
+        let group_size = group_size.unwrap_or(3);
+        match diagnostics::solve_checked(&raw_input, group_size, strictness) {
+            Ok(checked) => {
+                for anomaly in checked.anomalies.iter() {
+                    println!("{}", anomaly.describe());
+                }
+                println!("First solution: {:?}", checked.first);
+                println!("Second solution: {:?}", checked.second);
+            }
+            Err(anomalies) => {
+                for anomaly in anomalies.iter() {
+                    println!("{}", anomaly.describe());
+                }
+                println!("{} anomalies, no solution", anomalies.len());
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    let parsed = parse_input(raw_input);
+    // a custom table may have items without a puzzle priority, it replaces the puzzle one
+    if let Some(path) = priority::priorities_path_from_args(&args) {
+        let table = match PriorityTable::load(&path) {
+            Ok(table) => table,
+            Err(error) => {
+                eprintln!("{:?}", error);
+                std::process::exit(2);
+            }
+        };
+        println!("With {}, first: {}", path.display(), table.solve_one(&parsed));
+        match table.solve_groups(&parsed, group_size.unwrap_or(3)) {
+            Ok(sum) => println!("With {}, second: {}", path.display(), sum),
+            Err(error) => println!("With {}, second: {:?}", path.display(), error),
+        }
+        return;
+    }
+    if repack::repack_from_args(&args) {
+        let group_size = group_size.unwrap_or(3);
+        match plan_input(&parsed, group_size) {
+            Ok(groups) => print!("{}", repack::format_plans(&groups)),
+            Err(error) => println!("Groups of {}: {:?}", group_size, error),
+        }
+        return;
+    }
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    // groups of three unless `--group-size` asks for others, a bad grouping is not an answer
+    let group_size = group_size.unwrap_or(3);
+    match solve_groups(&parsed, group_size) {
+        Ok(second_solution) => println!("Second solution: {:?}", second_solution),
+        Err(error) => {
+            eprintln!("Groups of {}: {:?}", group_size, error);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod day3_test {
+    use super::*;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp\n
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n
+PmmdzqPrVvPwwTWBwg\n
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\n
+ttgJtRGJQctTZtZT\n
+CrZsJsPPZsGzwwsLwLmpwMDw\n";
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let expected = vec![
+            RuckSack {
+                left: "vJrwpWtwJgWr".chars().collect(),
+                right: "hcsFMMfFFhFp".chars().collect(),
+            },
+            RuckSack {
+                left: "jqHRNqRjqzjGDLGL".chars().collect(),
+                right: "rsFMfFZSrLrFZsSL".chars().collect(),
+            },
+            RuckSack {
+                left: "PmmdzqPrV".chars().collect(),
+                right: "vPwwTWBwg".chars().collect(),
+            },
+            RuckSack {
+                left: "wMqvLMZHhHMvwLH".chars().collect(),
+                right: "jbvcjnnSBnvTQFn".chars().collect(),
+            },
+            RuckSack {
+                left: "ttgJtRGJ".chars().collect(),
+                right: "QctTZtZT".chars().collect(),
+            },
+            RuckSack {
+                left: "CrZsJsPPZsGz".chars().collect(),
+                right: "wwsLwLmpwMDw".chars().collect(),
+            },
+        ];
+        assert_eq!(parsed[0].left, expected[0].left);
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn it_can_get_priority() {
+        assert_eq!(get_letter_priority('p'), 16);
+        assert_eq!(get_letter_priority('L'), 38);
+        assert_eq!(get_letter_priority('P'), 42);
+        assert_eq!(get_letter_priority('v'), 22);
+        assert_eq!(get_letter_priority('t'), 20);
+        assert_eq!(get_letter_priority('s'), 19);
+    }
+
+    #[test]
+    fn it_splits_on_characters() {
+        let rucksack = RuckSack::from("ééab");
+        assert_eq!(rucksack.left, vec!['é', 'é']);
+        assert_eq!(rucksack.right, vec!['a', 'b']);
+    }
+
+    #[test]
+    #[should_panic(expected = "No priority for item '1'")]
+    fn it_has_no_priority_for_digits() {
+        get_letter_priority('1');
+    }
+
+    #[test]
+    fn it_can_find_duplicate() {
+        let first_row = RuckSack {
+            left: "vJrwpWtwJgWr".chars().collect(),
+            right: "hcsFMMfFFhFp".chars().collect(),
+        };
+        assert_eq!((first_row.left_items() & first_row.right_items()).first(), Some('p'));
+        let nothing_shared = RuckSack::from("abcd");
+        assert!((nothing_shared.left_items() & nothing_shared.right_items()).is_empty());
+        assert_eq!(solve_one(vec![first_row, nothing_shared]), 16);
+    }
+
+    #[test]
+    fn it_can_use_rucksack_items() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        assert_eq!((parsed[0].left_items() & parsed[0].right_items()).single(), Some('p'));
+        assert_eq!(parsed[0].items(), ItemSet::from("vJrwpWtwJgWrhcsFMMfFFhFp"));
+    }
+
+    #[test]
+    fn it_can_solve_groups() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        assert_eq!(solve_groups(&parsed, 3), Ok(70));
+        assert_eq!(
+            solve_groups(&parsed[..4], 3),
+            Err(GroupError::PartialGroup { rucksacks: 1, group_size: 3 })
+        );
+        // the 6 rucksacks of the example share no item
+        assert_eq!(solve_groups(&parsed, 6), Err(GroupError::NoCommonItem { group: 0 }));
+        let args = |values: &[&str]| -> Vec<String> { values.iter().map(|x| x.to_string()).collect() };
+        assert_eq!(group_size_from_args(&args(&["day", "--group-size", "4"])), Ok(Some(4)));
+        assert_eq!(group_size_from_args(&args(&["day"])), Ok(None));
+        assert!(group_size_from_args(&args(&["day", "--group-size", "many"])).is_err());
+        assert!(group_size_from_args(&args(&["day", "--group-size"])).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "PartialGroup")]
+    fn it_refuses_an_incomplete_last_group() {
+        solve_two(parse_input("ab\ncc\nab\nad\n".to_string()));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{shared_items, solve_groups_by, solve_one_by, AdventResponse, GroupError, RuckSack};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Priority of each item type, items being any Unicode character
+#[derive(Debug, PartialEq, Clone)]
+pub struct PriorityTable {
+    priorities: HashMap<char, u32>,
+}
+
+/// Why a priority table cannot be read, lines are numbered from 1
+#[derive(Debug, PartialEq)]
+pub enum PriorityError {
+    MissingPriority { line: usize, text: String },
+    BadPriority { line: usize, priority: String },
+    // neither an item nor a "first-last" range
+    BadItems { line: usize, items: String },
+    Unreadable { path: PathBuf, reason: String },
+}
+
+/// The puzzle table: a-z are 1 to 26, A-Z are 27 to 52
+impl Default for PriorityTable {
+    fn default() -> Self {
+        PriorityTable::try_from("a-z 1\nA-Z 27").unwrap()
+    }
+}
+
+// one entry per line, "item priority" or "first-last priority" for a range
+// of consecutive characters numbered from `priority`, `#` starts a comment
+impl TryFrom<&str> for PriorityTable {
+    type Error = PriorityError;
+
+    fn try_from(item: &str) -> Result<Self, Self::Error> {
+        let mut priorities = HashMap::new();
+        for (i, line) in item.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((items, priority)) = line.rsplit_once(char::is_whitespace) else {
+                return Err(PriorityError::MissingPriority { line: i + 1, text: line.to_string() });
+            };
+            let Ok(priority) = priority.parse::<u32>() else {
+                return Err(PriorityError::BadPriority { line: i + 1, priority: priority.to_string() });
+            };
+            let chars: Vec<char> = items.trim().chars().collect();
+            let range = match chars.as_slice() {
+                [single] => *single..=*single,
+                [first, '-', last] if first <= last => *first..=*last,
+                _ => return Err(PriorityError::BadItems { line: i + 1, items: items.trim().to_string() }),
+            };
+            for (offset, c) in range.enumerate() {
+                priorities.insert(c, priority + offset as u32);
+            }
+        }
+        Ok(PriorityTable { priorities })
+    }
+}
+
+impl PriorityTable {
+    /// Reads a table written as for `TryFrom<&str>`
+    pub fn load(path: &Path) -> Result<Self, PriorityError> {
+        let content = std::fs::read_to_string(path).map_err(|e| PriorityError::Unreadable {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })?;
+        PriorityTable::try_from(content.as_str())
+    }
+
+    pub fn priority(&self, item: char) -> Option<u32> {
+        self.priorities.get(&item).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.priorities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.priorities.is_empty()
+    }
+
+    // panics for an item missing from the table
+    fn expect_priority(&self, item: char) -> u32 {
+        self.priority(item)
+            .unwrap_or_else(|| panic!("No priority for item {:?}", item))
+    }
+
+    /// Items found in every list, by priority then character
+    pub fn shared(&self, lists: &[&[char]]) -> Vec<char> {
+        shared_items(lists, |item| self.expect_priority(item))
+    }
+
+    /// Same as `solve_one` with this table, panics for an item missing from it
+    pub fn solve_one(&self, parsed: &[RuckSack]) -> AdventResponse {
+        solve_one_by(parsed, |item| self.expect_priority(item))
+    }
+
+    /// Same as `solve_groups` with this table
+    pub fn solve_groups(
+        &self,
+        parsed: &[RuckSack],
+        group_size: usize,
+    ) -> Result<AdventResponse, GroupError> {
+        solve_groups_by(parsed, group_size, |item| self.expect_priority(item))
+    }
+}
+
+/// `--priorities PATH` on the command line
+pub fn priorities_path_from_args(args: &[String]) -> Option<PathBuf> {
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--priorities" {
+            return iter.next().map(PathBuf::from);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod priority_test {
+    use super::*;
+    use crate::{get_letter_priority, parse_input, solve_one, solve_two};
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn it_has_the_puzzle_table_by_default() {
+        let table = PriorityTable::default();
+        assert_eq!(table.len(), 52);
+        for letter in ('a'..='z').chain('A'..='Z') {
+            assert_eq!(table.priority(letter), Some(get_letter_priority(letter)));
+        }
+        assert_eq!(table.priority('1'), None);
+        let parsed = parse_input(EXAMPLE.to_string());
+        assert_eq!(table.solve_one(&parsed), solve_one(parsed.clone()));
+        assert_eq!(table.solve_groups(&parsed, 3), Ok(solve_two(parsed.clone())));
+        assert!(table.solve_groups(&parsed, 4).is_err());
+    }
+
+    #[test]
+    fn it_can_read_custom_tables() {
+        let table =
+            PriorityTable::try_from("# digits then greek\n0-9 1\n\nα-ω 11 # final sigma too\né 100\n- 7").unwrap();
+        assert_eq!(table.priority('0'), Some(1));
+        assert_eq!(table.priority('9'), Some(10));
+        assert_eq!(table.priority('α'), Some(11));
+        assert_eq!(table.priority('ω'), Some(35));
+        assert_eq!(table.priority('é'), Some(100));
+        assert_eq!(table.priority('-'), Some(7));
+        assert_eq!(table.priority('a'), None);
+
+        let path = std::env::temp_dir().join(format!("day-03-priorities-{}", std::process::id()));
+        std::fs::write(&path, "a-c 5\n").unwrap();
+        assert_eq!(PriorityTable::load(&path).unwrap().priority('c'), Some(7));
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(PriorityTable::load(&path), Err(PriorityError::Unreadable { .. })));
+    }
+
+    #[test]
+    fn it_refuses_bad_tables() {
+        assert_eq!(
+            PriorityTable::try_from("a-z 1\nb"),
+            Err(PriorityError::MissingPriority { line: 2, text: "b".to_string() })
+        );
+        assert_eq!(
+            PriorityTable::try_from("a x"),
+            Err(PriorityError::BadPriority { line: 1, priority: "x".to_string() })
+        );
+        assert_eq!(
+            PriorityTable::try_from("z-a 1"),
+            Err(PriorityError::BadItems { line: 1, items: "z-a".to_string() })
+        );
+    }
+
+    #[test]
+    fn it_can_solve_unicode_rucksacks() {
+        let table = PriorityTable::try_from("α-ω 1\né 30\n0-9 40").unwrap();
+        // each line splits on characters, not bytes
+        let parsed = parse_input("αβéγδé\n1ω2ω\nπ1ρ9\n".to_string());
+        assert_eq!(table.shared(&[&parsed[0].left, &parsed[0].right]), vec!['é']);
+        assert_eq!(table.solve_one(&parsed), 30 + 25);
+        assert_eq!(table.solve_groups(&parsed, 3), Err(GroupError::NoCommonItem { group: 0 }));
+        assert_eq!(table.solve_groups(&parsed[1..], 2), Ok(41));
+    }
+
+    #[test]
+    #[should_panic(expected = "No priority for item 'x'")]
+    fn it_refuses_items_missing_from_the_table() {
+        PriorityTable::try_from("a-b 1").unwrap().solve_one(&parse_input("xaxb\n".to_string()));
+    }
+
+    #[test]
+    fn it_can_read_flag() {
+        let args: Vec<String> = ["day", "--priorities", "table.txt"].iter().map(|x| x.to_string()).collect();
+        assert_eq!(priorities_path_from_args(&args), Some(PathBuf::from("table.txt")));
+        assert_eq!(priorities_path_from_args(&args[..1]), None);
+    }
+}
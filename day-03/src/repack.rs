@@ -0,0 +1,320 @@
+use crate::{get_letter_priority, group_badges, GroupError, ItemSet, RuckSack};
+use std::collections::BTreeMap;
+
+/// Why a rucksack cannot be repacked
+#[derive(Debug, PartialEq, Clone)]
+pub enum RepackError {
+    // compartments cannot have the same size
+    OddSize { items: usize },
+    // no set of item types fills exactly one compartment
+    NoPartition,
+}
+
+/// How to repack a rucksack, and the rucksack once repacked
+#[derive(Debug, PartialEq, Clone)]
+pub struct RepackPlan {
+    // (item taken from the left, item taken from the right) of each swap
+    pub swaps: Vec<(char, char)>,
+    pub rucksack: RuckSack,
+}
+
+/// The fewest swaps between compartments so no item type is in both, sizes unchanged.
+/// Each type goes entirely to one side: the types kept on the left must fill it exactly,
+/// and every item of theirs coming from the right is one swap
+pub fn plan_repack(rucksack: &RuckSack) -> Result<RepackPlan, RepackError> {
+    let size = rucksack.left.len();
+    if rucksack.right.len() != size {
+        return Err(RepackError::OddSize {
+            items: size + rucksack.right.len(),
+        });
+    }
+    // (in the left, in the right) for each type, sorted so plans are the same every run
+    let mut counts: BTreeMap<char, (usize, usize)> = BTreeMap::new();
+    for item in rucksack.left.iter() {
+        counts.entry(*item).or_default().0 += 1;
+    }
+    for item in rucksack.right.iter() {
+        counts.entry(*item).or_default().1 += 1;
+    }
+    let types: Vec<(char, usize, usize)> = counts.into_iter().map(|(t, (l, r))| (t, l, r)).collect();
+
+    // best[i][s]: fewest swaps with `s` items on the left using the first `i` types
+    let mut best: Vec<Vec<Option<usize>>> = vec![vec![None; size + 1]; types.len() + 1];
+    best[0][0] = Some(0);
+    for (i, (_, left, right)) in types.iter().enumerate() {
+        for s in 0..=size {
+            let Some(swaps) = best[i][s] else { continue };
+            // the type goes right, its items on the left leave in swaps counted by the other side
+            if best[i + 1][s].is_none_or(|b| swaps < b) {
+                best[i + 1][s] = Some(swaps);
+            }
+            let kept = s + left + right;
+            if kept <= size && best[i + 1][kept].is_none_or(|b| swaps + right < b) {
+                best[i + 1][kept] = Some(swaps + right);
+            }
+        }
+    }
+    best[types.len()][size].ok_or(RepackError::NoPartition)?;
+
+    // walk back to find the types kept on the left
+    let mut kept_left = vec![];
+    let mut s = size;
+    for i in (0..types.len()).rev() {
+        let (item, left, right) = types[i];
+        if best[i][s] != best[i + 1][s] {
+            kept_left.push(item);
+            s -= left + right;
+        }
+    }
+
+    let mut repacked = rucksack.clone();
+    let leaving_left: Vec<usize> = (0..size)
+        .filter(|i| !kept_left.contains(&rucksack.left[*i]))
+        .collect();
+    let leaving_right: Vec<usize> = (0..size)
+        .filter(|i| kept_left.contains(&rucksack.right[*i]))
+        .collect();
+    let mut swaps = vec![];
+    for (l, r) in leaving_left.into_iter().zip(leaving_right) {
+        swaps.push((rucksack.left[l], rucksack.right[r]));
+        std::mem::swap(&mut repacked.left[l], &mut repacked.right[r]);
+    }
+    Ok(RepackPlan {
+        swaps,
+        rucksack: repacked,
+    })
+}
+
+/// Sum of the priorities of every item of a rucksack
+pub fn total_priority(rucksack: &RuckSack) -> u32 {
+    rucksack
+        .left
+        .iter()
+        .chain(rucksack.right.iter())
+        .map(|item| get_letter_priority(*item))
+        .sum()
+}
+
+/// Two elves of a group trading one item each, indexes in the input
+#[derive(Debug, PartialEq, Clone)]
+pub struct Exchange {
+    pub giver: usize,
+    pub receiver: usize,
+    // from the giver to the receiver
+    pub given: char,
+    // back to the giver, in the compartment `given` left
+    pub received: char,
+}
+
+/// Repacking of each rucksack of a group, then exchanges evening out their priorities
+#[derive(Debug, PartialEq, Clone)]
+pub struct GroupPlan {
+    pub plans: Vec<Result<RepackPlan, RepackError>>,
+    pub exchanges: Vec<Exchange>,
+    // total priority of each elf, once repacked and after the exchanges
+    pub priorities: Vec<u32>,
+}
+
+// the compartment of `rucksack` holding `item` then the other one
+fn compartments(rucksack: &mut RuckSack, item: char) -> Option<(&mut Vec<char>, &Vec<char>)> {
+    if rucksack.left.contains(&item) {
+        Some((&mut rucksack.left, &rucksack.right))
+    } else if rucksack.right.contains(&item) {
+        Some((&mut rucksack.right, &rucksack.left))
+    } else {
+        None
+    }
+}
+
+// swaps one `given` of `giver` with one `received` of `receiver` if both stay well packed
+fn try_exchange(giver: &mut RuckSack, receiver: &mut RuckSack, given: char, received: char) -> bool {
+    let (Some((giver_side, giver_other)), Some((receiver_side, receiver_other))) =
+        (compartments(giver, given), compartments(receiver, received))
+    else {
+        return false;
+    };
+    if giver_other.contains(&received) || receiver_other.contains(&given) {
+        return false;
+    }
+    let position = giver_side.iter().position(|item| *item == given).unwrap();
+    giver_side[position] = received;
+    let position = receiver_side.iter().position(|item| *item == received).unwrap();
+    receiver_side[position] = given;
+    true
+}
+
+// evens out the group by trading between its richest and poorest elf while it helps,
+// never changing the items shared by the whole group
+fn balance(group: &mut [RuckSack], first_index: usize) -> Vec<Exchange> {
+    let badge = ItemSet::intersect_all(group.iter().map(|r| r.items()));
+    let mut exchanges = vec![];
+    loop {
+        let priorities: Vec<u32> = group.iter().map(total_priority).collect();
+        let richest = (0..group.len()).max_by_key(|i| (priorities[*i], usize::MAX - i)).unwrap();
+        let poorest = (0..group.len()).min_by_key(|i| (priorities[*i], *i)).unwrap();
+        let spread = priorities[richest] - priorities[poorest];
+        // (distance to an even split, given, received) of the trades that narrow the spread
+        let mut candidates: Vec<(u32, char, char)> = vec![];
+        for given in group[richest].items().items() {
+            for received in group[poorest].items().items() {
+                let (pg, pr) = (get_letter_priority(given), get_letter_priority(received));
+                if pg > pr && pg - pr < spread {
+                    candidates.push((spread.abs_diff(2 * (pg - pr)), given, received));
+                }
+            }
+        }
+        candidates.sort();
+        let mut traded = false;
+        for (_, given, received) in candidates {
+            let (mut giver, mut receiver) = (group[richest].clone(), group[poorest].clone());
+            if !try_exchange(&mut giver, &mut receiver, given, received) {
+                continue;
+            }
+            let mut after = group.to_vec();
+            after[richest] = giver;
+            after[poorest] = receiver;
+            if ItemSet::intersect_all(after.iter().map(|r| r.items())) != badge {
+                continue;
+            }
+            group.clone_from_slice(&after);
+            exchanges.push(Exchange {
+                giver: first_index + richest,
+                receiver: first_index + poorest,
+                given,
+                received,
+            });
+            traded = true;
+            break;
+        }
+        if !traded {
+            return exchanges;
+        }
+    }
+}
+
+/// Repacks every rucksack, then evens out the priorities within each group of `group_size`.
+/// A group with a rucksack that cannot be repacked is not balanced
+pub fn plan_input(rucksacks: &[RuckSack], group_size: usize) -> Result<Vec<GroupPlan>, GroupError> {
+    group_badges(rucksacks, group_size)?;
+    Ok(rucksacks
+        .chunks(group_size)
+        .enumerate()
+        .map(|(g, group)| {
+            let plans: Vec<Result<RepackPlan, RepackError>> = group.iter().map(plan_repack).collect();
+            let mut repacked: Vec<RuckSack> = plans
+                .iter()
+                .zip(group.iter())
+                .map(|(plan, original)| plan.as_ref().map_or(original.clone(), |p| p.rucksack.clone()))
+                .collect();
+            let exchanges = if plans.iter().all(|plan| plan.is_ok()) {
+                balance(&mut repacked, g * group_size)
+            } else {
+                vec![]
+            };
+            GroupPlan {
+                plans,
+                exchanges,
+                priorities: repacked.iter().map(total_priority).collect(),
+            }
+        })
+        .collect())
+}
+
+/// One line per rucksack with its swaps, then the exchanges of each group
+pub fn format_plans(groups: &[GroupPlan]) -> String {
+    let mut report = String::new();
+    let mut index = 0;
+    for (g, group) in groups.iter().enumerate() {
+        for plan in group.plans.iter() {
+            let line = match plan {
+                Ok(plan) if plan.swaps.is_empty() => "nothing to move".to_string(),
+                Ok(plan) => plan
+                    .swaps
+                    .iter()
+                    .map(|(left, right)| format!("{}<->{}", left, right))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                Err(error) => format!("impossible: {:?}", error),
+            };
+            report.push_str(&format!("rucksack {}: {}\n", index, line));
+            index += 1;
+        }
+        for exchange in group.exchanges.iter() {
+            report.push_str(&format!(
+                "group {}: {} gives {} to {} for {}\n",
+                g, exchange.giver, exchange.given, exchange.receiver, exchange.received
+            ));
+        }
+        report.push_str(&format!("group {}: priorities {:?}\n", g, group.priorities));
+    }
+    report
+}
+
+/// `--repack` on the command line
+pub fn repack_from_args(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == "--repack")
+}
+
+#[cfg(test)]
+mod repack_test {
+    use super::*;
+    use crate::parse_input;
+
+    fn is_well_packed(rucksack: &RuckSack) -> bool {
+        rucksack.left.len() == rucksack.right.len()
+            && (rucksack.left_items() & rucksack.right_items()).is_empty()
+    }
+
+    #[test]
+    fn it_can_plan_fewest_swaps() {
+        let rucksack = RuckSack::from("vJrwpWtwJgWrhcsFMMfFFhFp");
+        let plan = plan_repack(&rucksack).unwrap();
+        // p goes to one side, an item found once on the other side takes its place
+        assert_eq!(plan.swaps.len(), 1);
+        assert!(is_well_packed(&plan.rucksack));
+        assert_eq!(plan.rucksack.items(), rucksack.items());
+
+        assert_eq!(plan_repack(&RuckSack::from("abba")).unwrap().swaps.len(), 1);
+        assert_eq!(plan_repack(&RuckSack::from("abcd")).unwrap().swaps, vec![]);
+        // b or c joins a on the left
+        let plan = plan_repack(&RuckSack::from("aabcbdcd")).unwrap();
+        assert_eq!(plan.swaps.len(), 1);
+        assert!(is_well_packed(&plan.rucksack));
+    }
+
+    #[test]
+    fn it_reports_impossible_cases() {
+        // 3 a and 1 b cannot fill 2 and 2
+        assert_eq!(plan_repack(&RuckSack::from("aaab")), Err(RepackError::NoPartition));
+        assert_eq!(
+            plan_repack(&RuckSack::from("abc")),
+            Err(RepackError::OddSize { items: 3 })
+        );
+    }
+
+    #[test]
+    fn it_can_balance_groups() {
+        // a is the badge, the first elf carries 103 and the second 10
+        let parsed = parse_input("zZxa\nbcda\n".to_string());
+        let groups = plan_input(&parsed, 2).unwrap();
+        assert_eq!(
+            groups[0].exchanges[0],
+            Exchange {
+                giver: 0,
+                receiver: 1,
+                given: 'Z',
+                received: 'd'
+            }
+        );
+        let priorities = &groups[0].priorities;
+        assert_eq!(priorities.iter().sum::<u32>(), 113);
+        assert!(priorities[0].abs_diff(priorities[1]) <= 3);
+        assert!(format_plans(&groups).contains("group 0: 0 gives Z to 1 for d"));
+
+        assert!(plan_input(&parsed, 3).is_err());
+        // an impossible rucksack leaves its group as it is
+        let groups = plan_input(&parse_input("aaab\nbcda\n".to_string()), 2).unwrap();
+        assert!(groups[0].exchanges.is_empty());
+        assert!(format_plans(&groups).contains("rucksack 0: impossible: NoPartition"));
+    }
+}
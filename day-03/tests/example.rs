@@ -0,0 +1,7 @@
+use day_03::get_letter_priority;
+
+#[test]
+fn it_can_get_priority() {
+    assert_eq!(get_letter_priority('a'), 1);
+    assert_eq!(get_letter_priority('Z'), 52);
+}
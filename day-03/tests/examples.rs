@@ -0,0 +1,3 @@
+// One test per fixture of examples/, generated by build.rs:
+// drop NAME.txt with NAME.part1 and/or NAME.part2 there to add a case.
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,220 @@
+//! Day 4: Camp Cleanup, overlapping section assignments.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 4;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    PairContained { pair: usize },
+    PairOverlap { pair: usize },
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct SectionInterval {
+    lower: u32,
+    upper: u32,
+}
+impl SectionInterval {
+    fn size(&self) -> u32 {
+        self.upper - self.lower
+    }
+}
+// so many unwraps
+impl From<String> for SectionInterval {
+    fn from(item: String) -> Self {
+        let (left, right) = item.split_once('-').unwrap();
+        SectionInterval {
+            lower: left.parse::<u32>().unwrap(),
+            upper: right.parse::<u32>().unwrap(),
+        }
+    }
+}
+
+pub type AdventParsed = Vec<(SectionInterval, SectionInterval)>;
+pub type AdventResponse = u32;
+
+/// Reads each line as a pair of section intervals
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut result = vec![];
+    // go over each line
+    for row in puzzle_input.split('\n') {
+        if row.is_empty() {
+            continue;
+        }
+        let (left, right) = row.split_once(',').unwrap();
+        let left_section = SectionInterval::from(left.to_string());
+        let right_section = SectionInterval::from(right.to_string());
+        result.push((left_section, right_section));
+    }
+    result
+}
+
+pub fn is_small_included_in_big(sec_big: &SectionInterval, sec_small: &SectionInterval) -> bool {
+    if sec_small.size() > sec_big.size() {
+        return false;
+    }
+    if sec_small.lower < sec_big.lower {
+        return false;
+    }
+    if sec_small.upper > sec_big.upper {
+        return false;
+    }
+    true
+}
+
+pub fn is_one_included(sec_a: &SectionInterval, sec_b: &SectionInterval) -> bool {
+    // we're also checking size in is_small_included_in_big
+    // println!("{:?} - {:?}", sec_a, sec_b);
+    // println!("size: {:?} - {:?}", sec_a.size(), sec_b.size());
+    if sec_a.size() > sec_b.size() {
+        is_small_included_in_big(sec_a, sec_b)
+    } else {
+        is_small_included_in_big(sec_b, sec_a)
+    }
+}
+
+/// Number of pairs where one interval contains the other
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    let mut result = 0;
+    for (pair, sections) in parsed.iter().enumerate() {
+        if is_one_included(&sections.0, &sections.1) {
+            trace::emit(TraceEvent::PairContained { pair });
+            result += 1;
+        }
+    }
+    result
+}
+
+pub fn is_left_overlap_with_right(sec_left: &SectionInterval, sec_right: &SectionInterval) -> bool {
+    (sec_left.upper >= sec_right.lower) || (sec_left.lower >= sec_right.upper)
+}
+
+pub fn pair_overlap(sec_a: &SectionInterval, sec_b: &SectionInterval) -> bool {
+    if sec_a.lower <= sec_b.lower {
+        is_left_overlap_with_right(sec_a, sec_b)
+    } else {
+        is_left_overlap_with_right(sec_b, sec_a)
+    }
+}
+
+/// Number of pairs that overlap at all
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    let mut result = 0;
+    for (pair, sections) in parsed.iter().enumerate() {
+        if pair_overlap(&sections.0, &sections.1) {
+            trace::emit(TraceEvent::PairOverlap { pair });
+            result += 1;
+        }
+    }
+    result
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed);
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day4_test {
+    use super::*;
+
+    const EXAMPLE: &str = "2-4,6-8\n
+2-3,4-5\n
+5-7,7-9\n
+2-8,3-7\n
+6-6,4-6\n
+2-6,4-8\n";
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let expected = vec![
+            (
+                SectionInterval { lower: 2, upper: 4 },
+                SectionInterval { lower: 6, upper: 8 },
+            ),
+            (
+                SectionInterval { lower: 2, upper: 3 },
+                SectionInterval { lower: 4, upper: 5 },
+            ),
+            (
+                SectionInterval { lower: 5, upper: 7 },
+                SectionInterval { lower: 7, upper: 9 },
+            ),
+            (
+                SectionInterval { lower: 2, upper: 8 },
+                SectionInterval { lower: 3, upper: 7 },
+            ),
+            (
+                SectionInterval { lower: 6, upper: 6 },
+                SectionInterval { lower: 4, upper: 6 },
+            ),
+            (
+                SectionInterval { lower: 2, upper: 6 },
+                SectionInterval { lower: 4, upper: 8 },
+            ),
+        ];
+        assert_eq!(parsed[0], expected[0]);
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn it_can_check_inclusion() {
+        // included
+        let res = is_small_included_in_big(
+            &SectionInterval { lower: 1, upper: 4 },
+            &SectionInterval { lower: 2, upper: 3 },
+        );
+        assert!(res);
+        // too big
+        let res = is_small_included_in_big(
+            &SectionInterval { lower: 1, upper: 5 },
+            &SectionInterval { lower: 4, upper: 6 },
+        );
+        assert!(!res);
+        // shifted
+        let res = is_small_included_in_big(
+            &SectionInterval { lower: 1, upper: 3 },
+            &SectionInterval { lower: 4, upper: 6 },
+        );
+        assert!(!res);
+    }
+
+    #[test]
+    fn it_find_inclusion() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        assert!(!is_one_included(&parsed[0].0, &parsed[0].1));
+        assert!(!is_one_included(&parsed[1].0, &parsed[1].1));
+        assert!(!is_one_included(&parsed[2].0, &parsed[2].1));
+        assert!(is_one_included(&parsed[3].0, &parsed[3].1));
+        assert!(is_one_included(&parsed[4].0, &parsed[4].1));
+        assert!(!is_one_included(&parsed[5].0, &parsed[5].1));
+    }
+
+    #[test]
+    fn it_can_find_overlap() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        assert!(!pair_overlap(&parsed[0].0, &parsed[0].1));
+        assert!(!pair_overlap(&parsed[1].0, &parsed[1].1));
+        assert!(pair_overlap(&parsed[2].0, &parsed[2].1));
+        assert!(pair_overlap(&parsed[3].0, &parsed[3].1));
+        assert!(pair_overlap(&parsed[4].0, &parsed[4].1));
+        assert!(pair_overlap(&parsed[5].0, &parsed[5].1));
+    }
+}
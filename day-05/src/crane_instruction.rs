@@ -1,8 +1,10 @@
-#[derive(Debug, PartialEq, Clone)]
-pub(crate) struct CraneInstruction {
-    pub(crate) quantity: u32,
-    pub(crate) from: u32,
-    pub(crate) to: u32,
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct CraneInstruction {
+    pub quantity: u32,
+    pub from: u32,
+    pub to: u32,
 }
 
 impl From<String> for CraneInstruction {
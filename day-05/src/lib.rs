@@ -0,0 +1,143 @@
+//! Day 5: Supply Stacks, crates moved by a crane.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub mod warehouse;
+pub mod crane_instruction;
+pub use warehouse::WarehouseState;
+pub use crane_instruction::CraneInstruction;
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 5;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    CratesMoved { part: u8, step: usize, quantity: u32, from: u32, to: u32 },
+}
+
+#[derive(Debug, PartialEq, Default, Clone, Serialize)]
+pub struct WareHouseAndInstructions<T> {
+    initial: WarehouseState<T>,
+    instructions: Vec<CraneInstruction>,
+}
+
+impl<T> WareHouseAndInstructions<T> {
+    pub fn initial(&self) -> &WarehouseState<T> {
+        &self.initial
+    }
+
+    pub fn instructions(&self) -> &[CraneInstruction] {
+        &self.instructions
+    }
+}
+
+pub type AdventParsed = WareHouseAndInstructions<char>;
+pub type AdventResponse = String;
+
+/// Splits the input into the drawing of the stacks and the crane instructions
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut buffer_warehouse = vec![];
+    let mut buffer_instr = vec![];
+    let mut end_warehouse = false;
+    for line in puzzle_input.split('\n').collect::<Vec<&str>>().iter() {
+        if line.is_empty() && !end_warehouse {
+            end_warehouse = true;
+        }
+        if end_warehouse {
+            if !line.is_empty() {
+                buffer_instr.push(*line);
+            }
+        } else {
+            buffer_warehouse.push(*line);
+        }
+    }
+    // println!("Parsing instructions");
+    let warehouse = WarehouseState::from(
+        String::from_iter(
+            buffer_warehouse.into_iter().map(
+                |x: &str| x.to_string() + "\n"
+            )
+        )
+    );
+    let instructions = buffer_instr.into_iter()
+        .map(|s: &str| CraneInstruction::from(s.to_string()))
+        .collect();
+    WareHouseAndInstructions {
+        initial: warehouse,
+        instructions
+    }
+}
+
+/// Tops of the stacks when crates are moved one by one
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    let mut warehouse = parsed.initial.clone();
+    for (step, instr) in parsed.instructions.iter().enumerate() {
+        trace::emit(TraceEvent::CratesMoved { part: 1, step, quantity: instr.quantity, from: instr.from, to: instr.to });
+        warehouse.execute_command(instr.clone());
+    }
+    let tops = warehouse.get_tops();
+    String::from_iter(tops.iter())
+}
+
+/// Tops of the stacks when the CrateMover 9001 moves crates together
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    let mut warehouse = parsed.initial.clone();
+    for (step, instr) in parsed.instructions.iter().enumerate() {
+        trace::emit(TraceEvent::CratesMoved { part: 2, step, quantity: instr.quantity, from: instr.from, to: instr.to });
+        warehouse.execute_command_9001(instr.clone());
+    }
+    let tops = warehouse.get_tops();
+    String::from_iter(tops.iter())
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed);
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day_test {
+    use super::*;
+
+    const EXAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let s = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 ";
+        let expected = WareHouseAndInstructions {
+            initial: WarehouseState::from(s.to_string()),
+            instructions: vec![
+                CraneInstruction::from("move 1 from 2 to 1".to_string()),
+                CraneInstruction::from("move 3 from 1 to 3".to_string()),
+                CraneInstruction::from("move 2 from 2 to 1".to_string()),
+                CraneInstruction::from("move 1 from 1 to 2".to_string())
+            ],
+        };
+        assert_eq!(parsed, expected);
+    }
+}
@@ -1,12 +1,19 @@
 use super::crane_instruction::CraneInstruction;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Default, Clone)]
-pub(crate) struct WarehouseState<T> {
+/// Stacks of crates, indexed from the left
+#[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
+pub struct WarehouseState<T> {
     piles: Vec<PileOfCrate<T>>
 }
 
 impl<T: Copy + Clone> WarehouseState<T> {
-    pub(crate) fn get_tops(&self) -> Vec<T> {
+    /// Crates of each stack, from the bottom
+    pub fn piles(&self) -> Vec<Vec<T>> {
+        self.piles.iter().map(|p| p.pile.clone()).collect()
+    }
+
+    pub fn get_tops(&self) -> Vec<T> {
         let mut result = vec![];
         for p in self.piles.iter() {
             result.push(*p.top().unwrap());
@@ -14,7 +21,7 @@ impl<T: Copy + Clone> WarehouseState<T> {
         result
     }
 
-    pub(crate) fn execute_command(&mut self, instr: CraneInstruction) {
+    pub fn execute_command(&mut self, instr: CraneInstruction) {
         let idx_from = instr.from - 1;
         let idx_to = instr.to - 1;
         for _ in 0..instr.quantity {
@@ -23,7 +30,7 @@ impl<T: Copy + Clone> WarehouseState<T> {
         }
     }
 
-    pub(crate) fn execute_command_9001(&mut self, instr: CraneInstruction) {
+    pub fn execute_command_9001(&mut self, instr: CraneInstruction) {
         let idx_from = instr.from - 1;
         let idx_to = instr.to - 1;
         let pulled: Vec<T> = self.piles[idx_from as usize]
@@ -34,7 +41,7 @@ impl<T: Copy + Clone> WarehouseState<T> {
     }
 }
 
-#[derive(Debug, PartialEq, Default, Clone)]
+#[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
 struct PileOfCrate<T> {
     pile: Vec<T>
 }
@@ -101,11 +108,8 @@ impl From<String> for WarehouseState<char> {
                     }
                 }
                 // println!("content: {:?}", letter);
-                match letter {
-                    Some(c) => {
-                        initial.piles[i].add_on_bottom(c);
-                    }
-                    None => {}
+                if let Some(c) = letter {
+                    initial.piles[i].add_on_bottom(c);
                 }
             }
         }
@@ -202,4 +206,13 @@ mod day_test {
         };
         assert_eq!(initial, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn it_can_serialize_state() {
+        let mut state = WarehouseState::from(EXAMPLE.to_string());
+        state.execute_command(CraneInstruction { quantity: 1, from: 2, to: 1 });
+        let saved = serde_json::to_string(&state).unwrap();
+        let restored: WarehouseState<char> = serde_json::from_str(&saved).unwrap();
+        assert_eq!(restored, state);
+    }
+}
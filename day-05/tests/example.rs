@@ -0,0 +1,12 @@
+use day_05::{CraneInstruction, WarehouseState};
+
+const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[test]
+fn it_can_move_crates() {
+    let drawing = EXAMPLE.split("\n\n").next().unwrap();
+    let mut warehouse = WarehouseState::from(drawing.to_string());
+    assert_eq!(warehouse.get_tops(), vec!['N', 'D', 'P']);
+    warehouse.execute_command(CraneInstruction::from("move 1 from 2 to 1".to_string()));
+    assert_eq!(warehouse.get_tops(), vec!['D', 'C', 'P']);
+}
@@ -0,0 +1,80 @@
+//! Day 6: Tuning Trouble, markers in a datastream.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+use std::collections::HashSet;
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 6;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    MarkerFound { scan_size: usize, position: u32 },
+}
+
+pub type AdventParsed = String;
+pub type AdventResponse = u32;
+
+/// Keeps the datastream as is, without the trailing newline
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    puzzle_input
+}
+
+pub fn is_unique_char(sliced: &[char]) -> bool {
+    let previous_len = sliced.len();
+    let set_sliced: HashSet<&char> = HashSet::from_iter(sliced.iter());
+    previous_len == set_sliced.len()
+}
+
+pub fn find_first_group_distinct(input_string: String, scan_size: usize) -> Option<u32> {
+    let chars: Vec<char> = input_string.chars().collect();
+    for i in 0..input_string.len() {
+        let sliced = &chars[i..i + scan_size];
+        if is_unique_char(sliced) {
+            let position = (i + scan_size) as AdventResponse;
+            trace::emit(TraceEvent::MarkerFound { scan_size, position });
+            return Some(position)
+        }
+    }
+    None
+}
+
+/// Characters read before the first start-of-packet marker
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    find_first_group_distinct(parsed, 4).unwrap()
+}
+
+/// Characters read before the first start-of-message marker
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    find_first_group_distinct(parsed, 14).unwrap()
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed);
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day_test {
+    use super::*;
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input("identity".to_string());
+        let expected = String::from("identity");
+        assert_eq!(parsed, expected);
+    }
+}
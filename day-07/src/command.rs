@@ -1,13 +1,15 @@
-#[derive(Debug, PartialEq, Clone)]
-pub(crate) enum CdDestination {
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub enum CdDestination {
     Root,
     Back,
     Next(String),
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub(crate) struct CdCommand {
-    pub(crate) destination: CdDestination,
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct CdCommand {
+    pub destination: CdDestination,
 }
 
 impl From<String> for CdCommand {
@@ -27,17 +29,17 @@ impl From<String> for CdCommand {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub(crate) enum LsElementType {
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub enum LsElementType {
     Directory,
     File,
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub(crate) struct LsElement {
-    pub(crate) ls_type: LsElementType,
-    pub(crate) name: String,
-    pub(crate) total_size: usize,
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct LsElement {
+    pub ls_type: LsElementType,
+    pub name: String,
+    pub total_size: usize,
 }
 
 impl From<String> for LsElement {
@@ -59,9 +61,9 @@ impl From<String> for LsElement {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub(crate) struct LsCommand {
-    pub(crate) elements: Vec<LsElement>,
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct LsCommand {
+    pub elements: Vec<LsElement>,
 }
 
 impl From<String> for LsCommand {
@@ -75,8 +77,8 @@ impl From<String> for LsCommand {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub(crate) enum Command {
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub enum Command {
     Cd(CdCommand),
     Ls(LsCommand),
 }
@@ -93,7 +95,7 @@ impl From<String> for Command {
     }
 }
 
-pub(crate) fn parse_commands(input_string: String) -> Vec<Command> {
+pub fn parse_commands(input_string: String) -> Vec<Command> {
     let mut result = vec![];
     for cmd_str in input_string.split('$').collect::<Vec<&str>>().iter() {
         let cmd_string = cmd_str.trim().to_string();
@@ -6,36 +6,37 @@ use super::command::{
 use std::collections::HashMap;
 
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) enum FileType {
+pub enum FileType {
     Directory,
     File,
 }
 
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) struct File {
+pub struct File {
     file_type: FileType,
-    pub(crate) name: String,
-    pub(crate) total_size: usize,
+    pub name: String,
+    pub total_size: usize,
     dirname: Option<String>,
 }
 
 impl File {
-    fn full_path(&self) -> String {
+    pub fn full_path(&self) -> String {
         match self.dirname.clone() {
             None => self.name.clone(),
             Some(d) => {
-                vec![d, self.name.clone()].join("/").replace("//", "/")
+                [d, self.name.clone()].join("/").replace("//", "/")
             }
         }    
     }
 }
 
+/// Files and directories found in the terminal log, by full path
 #[derive(Default, Debug, PartialEq)]
-pub(crate) struct FileTree {
+pub struct FileTree {
     files: HashMap<String, File>,
 }
 
-fn compare_slashes(a: &String, b: &String) -> std::cmp::Ordering {
+fn compare_slashes(a: &str, b: &str) -> std::cmp::Ordering {
     let a_l = a.matches("/").collect::<Vec<&str>>().len();
     let b_l = b.matches("/").collect::<Vec<&str>>().len();
     a_l.partial_cmp(&b_l).unwrap()
@@ -50,11 +51,11 @@ impl FileTree {
         let mut sorted_abs_path = self.files.keys().cloned().collect::<Vec<String>>();
         sorted_abs_path.sort_by(|a, b| compare_slashes(a, b));
         sorted_abs_path.reverse();
-        let mut result = vec![];
+        let result = vec![];
         // println!("{:?}", sorted_abs_path);
         for key in sorted_abs_path.iter() {
             let file = self.files.get(key).unwrap().clone();
-            if file.name == "/".to_string() {
+            if file.name == "/" {
                 continue
             }
             let dirname = file.dirname.unwrap().clone();
@@ -79,7 +80,7 @@ impl FileTree {
         result
     }
 
-    pub(crate) fn get_directories(&self) -> Vec<File> {
+    pub fn get_directories(&self) -> Vec<File> {
         let mut keys: Vec<&String> = self.files.keys().clone().collect();
         keys.sort();
         let mut result = vec![];
@@ -0,0 +1,81 @@
+//! Day 7: No Space Left On Device, sizes of a file tree rebuilt from a terminal log.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub mod command;
+pub mod file_tree;
+pub use command::{parse_commands, Command};
+pub use file_tree::{FileTree, File};
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 7;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    DirectorySize { path: String, size: usize },
+}
+
+pub type AdventParsed = Vec<Command>;
+pub type AdventResponse = usize;
+
+/// Reads the terminal log as commands with their output
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    parse_commands(puzzle_input)
+}
+
+/// Sum of the sizes of the directories of at most 100000
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    let ft = FileTree::from(parsed);
+    let dirs = ft.get_directories();
+    let mut result = 0;
+    for dir in dirs {
+        trace::emit(TraceEvent::DirectorySize { path: dir.full_path(), size: dir.total_size });
+        if dir.total_size <= 100000 {
+            result += dir.total_size;
+        }
+    }
+    result
+}
+
+/// Size of the smallest directory to delete to get enough free space
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    let ft = FileTree::from(parsed);
+    let total_space = 70000000;
+    let needed_space = 30000000;
+    // size of outermost directory
+    let files = ft.get_directories();
+    let root_folder = files.iter().filter(|x| x.name == "/").collect::<Vec<&File>>()[0];
+    let outermost_size = root_folder.total_size;
+    // println!("outermost: {:?}", outermost_size);
+    // min size = needed - (total - outermost)
+    let unused_space = total_space - outermost_size;
+    let min_size = needed_space - unused_space;
+    // println!("min_size: {:?}", min_size);
+    // iterate over folders
+    let mut result = outermost_size;
+    for dir in files.iter() {
+        // println!("dir: {:?}", dir.total_size);
+        if dir.total_size >= min_size && dir.total_size < result  {
+            result = dir.total_size;
+        }
+    }
+    result
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed);
+    println!("Second solution: {:?}", second_solution);
+}
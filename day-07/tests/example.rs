@@ -0,0 +1,11 @@
+use day_07::{parse_input, FileTree};
+
+const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[test]
+fn it_can_build_file_tree() {
+    let tree = FileTree::from(parse_input(EXAMPLE.to_string()));
+    let directories = tree.get_directories();
+    let root = directories.iter().find(|x| x.name == "/").unwrap();
+    assert_eq!(root.total_size, 48381165);
+}
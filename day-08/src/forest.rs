@@ -1,5 +1,9 @@
-#[derive(Debug, PartialEq, Clone, Copy)]
-enum Direction {
+use crate::{trace, TraceEvent};
+use serde::Serialize;
+
+/// Side the trees are looked at from
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+pub enum Direction {
     Up, 
     Down,
     Left,
@@ -7,7 +11,7 @@ enum Direction {
 }
 
 #[derive(Debug, PartialEq, Clone, Default)]
-pub(crate) struct Tree {
+pub struct Tree {
     value: u8,
     visible_up: bool,
     visible_down: bool,
@@ -17,9 +21,10 @@ pub(crate) struct Tree {
 
 impl Tree {
     fn new(value: u8) -> Self {
-        let mut tree = Tree::default();
-        tree.value = value;
-        tree
+        Tree {
+            value,
+            ..Tree::default()
+        }
     }
 
     fn update_visibility(&mut self, is_visible: bool, direction: Direction) {
@@ -39,11 +44,11 @@ impl Tree {
         }
     }
     
-    pub(crate) fn is_any_visible(&self) -> bool {
+    pub fn is_any_visible(&self) -> bool {
         self.visible_up || self.visible_down || self.visible_left || self.visible_right
     }
 
-    pub(crate) fn is_all_visible(&self) -> bool {
+    pub fn is_all_visible(&self) -> bool {
         self.visible_up && self.visible_down && self.visible_left && self.visible_right
     }
 }
@@ -66,8 +71,9 @@ fn visible_trees(trees: &[Tree]) -> Vec<bool> {
     result
 }
 
+/// Grid of trees, with the visibility of each tree from the four sides
 #[derive(Debug, PartialEq, Clone, Default)]
-pub(crate) struct Forest {
+pub struct Forest {
     trees: Vec<Vec<Tree>>
 }
 
@@ -86,7 +92,7 @@ impl From<Vec<Vec<u32>>> for Forest {
 }
 
 impl Forest {
-    pub(crate) fn get_size(&self) -> (usize, usize) {
+    pub fn get_size(&self) -> (usize, usize) {
         (self.trees.len(), self.trees[0].len())
     }
 
@@ -148,11 +154,10 @@ impl Forest {
     }
 
     fn update_visibility_direction(&mut self, direction: Direction) {
-        let mut limit = 0;
-        match direction {
-            Direction::Up|Direction::Down => { limit = self.trees.len() },
-            Direction::Left|Direction::Right => { limit = self.trees[0].len() }
-        }
+        let limit = match direction {
+            Direction::Up|Direction::Down => self.trees.len(),
+            Direction::Left|Direction::Right => self.trees[0].len(),
+        };
         for i in 0..limit {
             // let mut mrow = self.get_trees_mut(i, direction);
             let row = self.get_trees(i, direction);
@@ -163,21 +168,52 @@ impl Forest {
                 // if l == 1 && u == 2 {
                 //     println!("{:?}->{:?}", direction, vis);
                 // }
+                if *vis {
+                    trace::emit(TraceEvent::TreeVisible { row: l, col: u, direction });
+                }
                 self.trees[l][u].update_visibility(*vis, direction);
             }
         }
     }
 
-    pub(crate) fn update_visibility(&mut self) {
+    pub fn update_visibility(&mut self) {
         self.update_visibility_direction(Direction::Up);
         self.update_visibility_direction(Direction::Down);
         self.update_visibility_direction(Direction::Left);
         self.update_visibility_direction(Direction::Right);
     }
 
-    pub(crate) fn get_trees_flatten(&self) -> Vec<Tree> {
+    pub fn get_trees_flatten(&self) -> Vec<Tree> {
         self.trees.iter().flatten().cloned().collect()
     }
+
+    // trees seen from (row, col) looking towards `direction`, up to the first as tall
+    fn viewing_distance(&self, row: usize, col: usize, direction: Direction) -> u32 {
+        let (rows, cols) = self.get_size();
+        let height = self.trees[row][col].value;
+        let line: Vec<(usize, usize)> = match direction {
+            Direction::Up => (0..row).rev().map(|r| (r, col)).collect(),
+            Direction::Down => (row + 1..rows).map(|r| (r, col)).collect(),
+            Direction::Left => (0..col).rev().map(|c| (row, c)).collect(),
+            Direction::Right => (col + 1..cols).map(|c| (row, c)).collect(),
+        };
+        let mut distance = 0;
+        for (r, c) in line {
+            distance += 1;
+            if self.trees[r][c].value >= height {
+                break;
+            }
+        }
+        distance
+    }
+
+    /// Product of the viewing distances in the four directions
+    pub fn scenic_score(&self, row: usize, col: usize) -> u32 {
+        [Direction::Up, Direction::Down, Direction::Left, Direction::Right]
+            .iter()
+            .map(|direction| self.viewing_distance(row, col, *direction))
+            .product()
+    }
 }
 
 
@@ -196,19 +232,19 @@ mod forest_test {
         ];
         let forest = Forest::from(parsed);
         let first_left = forest.get_trees(0, Direction::Left);
-        let expected: Vec<Tree> = vec![3, 0, 3, 7, 3].iter().map(|x| Tree::new(*x)).collect();
+        let expected: Vec<Tree> = [3, 0, 3, 7, 3].iter().map(|x| Tree::new(*x)).collect();
         assert_eq!(first_left, expected);
      
         let last_right = forest.get_trees(4, Direction::Right);
-        let expected: Vec<Tree> = vec![0, 9, 3, 5, 3].iter().map(|x| Tree::new(*x)).collect();
+        let expected: Vec<Tree> = [0, 9, 3, 5, 3].iter().map(|x| Tree::new(*x)).collect();
         assert_eq!(last_right, expected);
 
         let one_up = forest.get_trees(1, Direction::Up);
-        let expected: Vec<Tree> = vec![0, 5, 5, 3, 5].iter().map(|x| Tree::new(*x)).collect();
+        let expected: Vec<Tree> = [0, 5, 5, 3, 5].iter().map(|x| Tree::new(*x)).collect();
         assert_eq!(one_up, expected);
 
         let penul_down = forest.get_trees(3, Direction::Down);
-        let expected: Vec<Tree> = vec![9, 4, 3, 1, 7].iter().map(|x| Tree::new(*x)).collect();
+        let expected: Vec<Tree> = [9, 4, 3, 1, 7].iter().map(|x| Tree::new(*x)).collect();
         assert_eq!(penul_down, expected);
     }
 
@@ -233,6 +269,26 @@ mod forest_test {
         assert_eq!(visible, expected);
     }
 
+    #[test]
+    fn it_can_compute_scenic_score() {
+        let parsed: Vec<Vec<u32>> = vec![
+            vec![3, 0, 3, 7, 3],
+            vec![2, 5, 5, 1, 2],
+            vec![6, 5, 3, 3, 2],
+            vec![3, 3, 5, 4, 9],
+            vec![3, 5, 3, 9, 0],
+        ];
+        let forest = Forest::from(parsed);
+        assert_eq!(forest.viewing_distance(1, 2, Direction::Up), 1);
+        assert_eq!(forest.viewing_distance(1, 2, Direction::Left), 1);
+        assert_eq!(forest.viewing_distance(1, 2, Direction::Right), 2);
+        assert_eq!(forest.viewing_distance(1, 2, Direction::Down), 2);
+        assert_eq!(forest.scenic_score(1, 2), 4);
+        assert_eq!(forest.scenic_score(3, 2), 8);
+        // an edge tree sees nothing on one side
+        assert_eq!(forest.scenic_score(0, 3), 0);
+    }
+
     #[test]
     fn it_can_update_visibility() {
         let parsed: Vec<Vec<u32>> = vec![
@@ -0,0 +1,103 @@
+//! Day 8: Treetop Tree House, visibility in a grid of trees.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub mod forest;
+pub use forest::{Direction, Forest};
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 8;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    TreeVisible { row: usize, col: usize, direction: Direction },
+}
+
+pub type AdventParsed = Vec<Vec<u32>>;
+pub type AdventResponse = u32;
+
+/// Reads the grid of tree heights
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut result = vec![];
+    for row in puzzle_input.split('\n').collect::<Vec<&str>>() {
+        let clean_row = row.trim();
+        if clean_row.is_empty() {
+            continue
+        }
+        let chars = clean_row.chars().collect::<Vec<char>>();
+        // to_digit returns a u32
+        let line = chars.iter().map(|x| x.to_digit(10).unwrap() ).collect();
+        result.push(line);
+    }
+    result
+}
+
+/// Number of trees visible from outside the grid
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    let mut result = 0;
+    let mut ft = Forest::from(parsed);
+    ft.update_visibility();
+    for t in ft.get_trees_flatten().iter() {
+        if t.is_any_visible() {
+            result += 1;
+        }
+    }
+    result
+}
+
+/// Best scenic score of the trees
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    let ft = Forest::from(parsed);
+    let (rows, cols) = ft.get_size();
+    let mut result = 0;
+    for row in 0..rows {
+        for col in 0..cols {
+            result = result.max(ft.scenic_score(row, col));
+        }
+    }
+    result
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed);
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day_test {
+    use super::*;
+
+    const EXAMPLE: &str = "30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let expected = vec![
+            vec![3, 0, 3, 7, 3],
+            vec![2, 5, 5, 1, 2],
+            vec![6, 5, 3, 3, 2],
+            vec![3, 3, 5, 4, 9],
+            vec![3, 5, 3, 9, 0],
+        ];
+        assert_eq!(parsed[0], expected[0]);
+        assert_eq!(parsed, expected);
+    }
+}
@@ -0,0 +1,11 @@
+use day_08::{parse_input, Forest};
+
+const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[test]
+fn it_can_see_trees_on_the_edge() {
+    let mut forest = Forest::from(parse_input(EXAMPLE.to_string()));
+    forest.update_visibility();
+    assert_eq!(forest.get_size(), (5, 5));
+    assert!(forest.get_trees_flatten()[0].is_any_visible());
+}
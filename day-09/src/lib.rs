@@ -0,0 +1,165 @@
+//! Day 9: Rope Bridge, a rope tail following its head.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+use std::collections::HashSet;
+pub mod move_func;
+pub mod movement;
+pub mod point;
+pub mod rope;
+pub use move_func::{apply_movement, follow_head};
+pub use movement::Movement;
+pub use point::Point;
+pub use rope::Rope;
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 9;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    // only the first visit of each position
+    TailVisited { knots: usize, x: isize, y: isize },
+}
+
+pub type AdventParsed = Vec<Movement>;
+pub type AdventResponse = usize;
+
+/// Reads each line as a movement
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut parsed = vec![];
+    for line in puzzle_input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+        parsed.push(Movement::from(line.trim().to_string()));
+    }
+    parsed
+}
+
+pub fn follow_movements(parsed: AdventParsed) -> HashSet<Point> {
+    let mut result: HashSet<Point> = HashSet::new();
+    let mut head = Point { x: 0, y: 0 };
+    let mut tail = Point { x: 0, y: 0 };
+    for movement in parsed {
+        // println!(">> going {:?}", movement);
+        let unaries = movement.split_unit();
+        for unit_mov in unaries {
+            // head is always moving
+            head = apply_movement(&head, &unit_mov);
+            // println!("Moving head to {:?}", head.clone());
+            // first head movement
+            let tail_mov = follow_head(&head, &tail);
+            tail = apply_movement(&tail, &tail_mov);
+            let added = result.insert(tail.clone());
+            if added {
+                trace::emit(TraceEvent::TailVisited { knots: 2, x: tail.x, y: tail.y });
+            }
+            // println!("tail {:?}", tail.clone());
+            // if added {
+            // println!("adding {:?}", tail.clone());
+            // }
+            // tail is moving if it's not the last move
+            // if tail == head {
+            //     panic!("tail over head: h:{:?} - t:{:?}", head.clone(), tail.clone());
+            // }
+        }
+    }
+    result
+}
+
+/// Positions visited by the tail of a rope of 2 knots
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    let positions = follow_movements(parsed);
+    positions.len()
+}
+
+/// Positions visited by the tail of a rope of 10 knots
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    let mut rope = Rope::new(Point::new(0, 0), 10);
+    for movement in parsed {
+        rope.apply_movement(movement);
+    }
+    let tail_mem = rope.get_tail_memory();
+    tail_mem.len()
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed);
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day_test {
+    use super::*;
+
+    const EXAMPLE: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let expected = vec![
+            Movement::new(4, 0),
+            Movement::new(0, 4),
+            Movement::new(-3, 0),
+            Movement::new(0, -1),
+            Movement::new(4, 0),
+            Movement::new(0, -1),
+            Movement::new(-5, 0),
+            Movement::new(2, 0),
+        ];
+        assert_eq!(parsed[0], expected[0]);
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn it_can_follow_positions() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let result = follow_movements(parsed);
+        let expected = HashSet::from([
+            //
+            Point::new(1, 0),
+            Point::new(2, 0),
+            Point::new(3, 0),
+            Point::new(4, 1),
+            Point::new(4, 2),
+            //
+            Point::new(4, 3),
+            Point::new(0, 0),
+            Point::new(3, 4),
+            Point::new(2, 4),
+            Point::new(3, 3),
+            //
+            Point::new(3, 2),
+            Point::new(2, 2),
+            Point::new(1, 2),
+        ]);
+        assert_eq!(result.len(), expected.len());
+        // if result != expected {
+        //     let sub = &expected - &result;
+        //     println!("sub: {:?}", sub);
+        //     let osub = &result - &expected;
+        //     println!("osub: {:?}", osub);
+        // }
+        assert_eq!(result, expected);
+    }
+}
@@ -1,7 +1,9 @@
-#[derive(Debug, PartialEq, Clone)]
-pub(crate) struct Movement {
-    pub(crate) x: isize,
-    pub(crate) y: isize,
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct Movement {
+    pub x: isize,
+    pub y: isize,
 }
 
 impl From<String> for Movement {
@@ -30,11 +32,11 @@ impl From<String> for Movement {
 }
 
 impl Movement {
-    pub(crate) fn new(x: isize, y: isize) -> Self {
+    pub fn new(x: isize, y: isize) -> Self {
         Movement { x, y }
     }
 
-    pub(crate) fn make_unary(&self) -> Movement {
+    pub fn make_unary(&self) -> Movement {
         let xnum: usize = self.x.abs().try_into().unwrap();
         let ynum: usize = self.y.abs().try_into().unwrap();
         let mut new_x = 0;
@@ -48,7 +50,7 @@ impl Movement {
         Movement { x: new_x, y: new_y }
     }
 
-    pub(crate) fn split_unit(&self) -> Vec<Movement> {
+    pub fn split_unit(&self) -> Vec<Movement> {
         let unary = self.make_unary();
         if self.x != 0 {
             let num: usize = self.x.abs().try_into().unwrap();
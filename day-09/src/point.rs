@@ -1,11 +1,13 @@
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub(crate) struct Point {
-    pub(crate) x: isize,
-    pub(crate) y: isize,
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
 }
 
 impl Point {
-    pub(crate) fn new(x: isize, y: isize) -> Self {
+    pub fn new(x: isize, y: isize) -> Self {
         Point { x, y }
     }
 }
@@ -1,17 +1,20 @@
 use crate::move_func::{apply_movement, follow_head};
 use crate::movement::Movement;
 use crate::point::Point;
+use crate::{trace, TraceEvent};
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub(crate) struct Rope {
+/// Knots of a rope, the first one being the head
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Rope {
     nb_nodes: usize,
     nodes: Vec<Point>,
     tail_memory: HashSet<Point>,
 }
 
 impl Rope {
-    pub(crate) fn new(start: Point, length: usize) -> Self {
+    pub fn new(start: Point, length: usize) -> Self {
         Rope {
             nb_nodes: length,
             nodes: vec![start; length],
@@ -19,7 +22,7 @@ impl Rope {
         }
     }
 
-    pub(crate) fn apply_movement(&mut self, mov: Movement) {
+    pub fn apply_movement(&mut self, mov: Movement) {
         let unaries = mov.split_unit();
         for unit_mov in unaries {
             // move head,
@@ -35,14 +38,20 @@ impl Rope {
                 let node_mov = follow_head(&self.nodes[i - 1], &self.nodes[i]);
                 self.nodes[i] = apply_movement(&self.nodes[i], &node_mov);
             }
-            self.tail_memory
-                .insert(self.nodes[self.nb_nodes - 1].clone());
+            let tail = self.nodes[self.nb_nodes - 1].clone();
+            if self.tail_memory.insert(tail.clone()) {
+                trace::emit(TraceEvent::TailVisited { knots: self.nb_nodes, x: tail.x, y: tail.y });
+            }
         }
     }
 
-    pub(crate) fn get_tail_memory(&self) -> HashSet<Point> {
+    pub fn get_tail_memory(&self) -> HashSet<Point> {
         self.tail_memory.clone()
     }
+
+    pub fn get_knots(&self) -> Vec<Point> {
+        self.nodes.clone()
+    }
 }
 
 #[cfg(test)]
@@ -59,6 +68,7 @@ L 5
 R 2";
 
     // rope with length of two should solve first part
+    #[test]
     fn it_can_solve_first_part() {
         let mut parsed = vec![];
         for line in EXAMPLE.to_string().lines() {
@@ -68,10 +78,24 @@ R 2";
             parsed.push(Movement::from(line.trim().to_string()));
         }
 
-        let rope_2 = Rope::new(Point::new(0, 0), 2);
+        let mut rope_2 = Rope::new(Point::new(0, 0), 2);
+        for movement in parsed {
+            rope_2.apply_movement(movement);
+        }
 
         let tail_mem = rope_2.get_tail_memory();
 
         assert_eq!(tail_mem.len(), 13);
     }
+
+    #[test]
+    fn it_can_serialize_rope() {
+        let mut rope = Rope::new(Point::new(0, 0), 10);
+        for line in EXAMPLE.lines() {
+            rope.apply_movement(Movement::from(line.to_string()));
+        }
+        let saved = serde_json::to_string(&rope).unwrap();
+        let restored: Rope = serde_json::from_str(&saved).unwrap();
+        assert_eq!(restored, rope);
+    }
 }
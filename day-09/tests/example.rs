@@ -0,0 +1,8 @@
+use day_09::{Movement, Point, Rope};
+
+#[test]
+fn it_can_move_rope() {
+    let mut rope = Rope::new(Point::new(0, 0), 2);
+    rope.apply_movement(Movement::from("R 4".to_string()));
+    assert_eq!(rope.get_tail_memory().len(), 4);
+}
@@ -0,0 +1,81 @@
+// the capital letters of the puzzle font, 4 pixels wide and 6 tall, rows from the top
+const GLYPHS: [(char, [&str; 6]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters shown by a drawing of `draw_crt`, each 4 pixels wide followed by a blank column,
+/// None if the drawing is not made of known letters
+pub fn read_letters(drawing: &str) -> Option<String> {
+    let rows: Vec<&str> = drawing.lines().collect();
+    let width = rows.first().map_or(0, |row| row.len());
+    if rows.len() != 6 || width == 0 || rows.iter().any(|row| row.len() != width || !row.is_ascii())
+    {
+        return None;
+    }
+    let mut letters = String::new();
+    for left in (0..width).step_by(5) {
+        let glyph: Vec<&str> = rows
+            .iter()
+            .map(|row| row.get(left..left + 4))
+            .collect::<Option<_>>()?;
+        let (letter, _) = GLYPHS.iter().find(|(_, pixels)| pixels[..] == glyph[..])?;
+        letters.push(*letter);
+    }
+    Some(letters)
+}
+
+#[cfg(test)]
+mod letters_test {
+    use super::*;
+
+    // the letters side by side, as the CRT draws them
+    fn draw(letters: &str) -> String {
+        (0..6)
+            .map(|row| {
+                letters
+                    .chars()
+                    .map(|letter| {
+                        let (_, pixels) = GLYPHS.iter().find(|(l, _)| *l == letter).unwrap();
+                        format!("{}.", pixels[row])
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn it_can_read_letters() {
+        assert_eq!(
+            read_letters(&draw("BJFRHRFU")),
+            Some("BJFRHRFU".to_string())
+        );
+        assert_eq!(
+            read_letters(&draw("ACEGKLOPSZ")),
+            Some("ACEGKLOPSZ".to_string())
+        );
+    }
+
+    #[test]
+    fn it_refuses_other_drawings() {
+        let example = include_str!("../examples/example.part2");
+        assert_eq!(read_letters(example), None);
+        assert_eq!(read_letters(""), None);
+        assert_eq!(read_letters(&draw("AB")[..20]), None);
+    }
+}
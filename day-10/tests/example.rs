@@ -0,0 +1,7 @@
+use day_10::{apply_commands, parse_input};
+
+#[test]
+fn it_can_apply_commands() {
+    let parsed = parse_input("noop\naddx 3\naddx -5".to_string());
+    assert_eq!(apply_commands(parsed), vec![1, 1, 1, 4, 4, -1]);
+}
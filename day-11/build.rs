@@ -0,0 +1,4 @@
+// one test per examples/ fixture, see advent_common::fixtures
+fn main() {
+    advent_common::fixtures::write_example_tests();
+}
@@ -0,0 +1,178 @@
+//! Day 11: Monkey in the Middle, monkeys throwing items around.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub mod monkey;
+pub use advent_common::Checkpoint;
+pub use monkey::{MonkeyArena, Monkey, Operation, compute_monkey_business, compute_monkey_business_checkpointed};
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 11;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    ItemThrown { round: usize, from: usize, to: usize, worry: f64 },
+}
+
+pub type AdventParsed = MonkeyArena;
+pub type AdventResponse = usize;
+
+// rounds of the second part between two checkpoints
+const CHECKPOINT_EVERY: usize = 500;
+
+/// Reads each paragraph as a monkey of the arena
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut arena = MonkeyArena::new();
+    for monkey_lines in puzzle_input.split("\n\n") {
+        // println!("{:?}", monkey_lines);
+        let monkey = Monkey::from(monkey_lines.to_string());
+        arena.monkeys.push(monkey);
+    }
+    arena
+}
+
+/// Monkey business after 20 rounds
+pub fn solve_one(mut arena: AdventParsed) -> AdventResponse {
+    let total_round = 20;
+    for _ in 0..(total_round) {
+        arena.play_round();
+    }
+    let mut businesses = arena.get_monkey_business();
+    // println!("businesses: {:?}", businesses);
+    businesses.sort();
+    businesses.reverse();
+    let top_two = &businesses[..2];
+    // println!("top_two: {:?}", top_two);
+    top_two[0] * top_two[1]
+}
+
+fn top_two_product(mut businesses: Vec<usize>) -> AdventResponse {
+    businesses.sort();
+    businesses.reverse();
+    let top_two = &businesses[..2];
+    top_two[0] * top_two[1]
+}
+
+/// Monkey business after 10000 rounds, without relief
+pub fn solve_two(arena: AdventParsed) -> AdventResponse {
+    top_two_product(compute_monkey_business(arena, 10_000, true))
+}
+
+/// Same as `solve_two`, saving the arena to `checkpoint` and resuming from it
+pub fn solve_two_checkpointed(arena: AdventParsed, checkpoint: &Checkpoint) -> AdventResponse {
+    top_two_product(compute_monkey_business_checkpointed(arena, 10_000, true, checkpoint))
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input.clone());
+    let first_solution = solve_one(parsed);
+    println!("First solution: {:?}", first_solution);
+    let parsed = parse_input(raw_input);
+    // `--checkpoint PATH` to resume an interrupted run
+    let second_solution = match Checkpoint::from_env(CHECKPOINT_EVERY) {
+        Some(checkpoint) => solve_two_checkpointed(parsed, &checkpoint),
+        None => solve_two(parsed),
+    };
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day_11_test {
+    use super::*;
+    use crate::monkey::arena_fingerprint;
+
+    const INPUT_MONKEYS: &str = "Monkey 0:
+    Starting items: 79, 98
+    Operation: new = old * 19
+    Test: divisible by 23
+      If true: throw to monkey 2
+      If false: throw to monkey 3
+
+  Monkey 1:
+    Starting items: 54, 65, 75, 74
+    Operation: new = old + 6
+    Test: divisible by 19
+      If true: throw to monkey 2
+      If false: throw to monkey 0
+
+  Monkey 2:
+    Starting items: 79, 60, 97
+    Operation: new = old * old
+    Test: divisible by 13
+      If true: throw to monkey 1
+      If false: throw to monkey 3
+
+  Monkey 3:
+    Starting items: 74
+    Operation: new = old + 3
+    Test: divisible by 17
+      If true: throw to monkey 0
+      If false: throw to monkey 1";
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input(INPUT_MONKEYS.to_string());
+        // let expected = vec![
+        // ];
+        // assert_eq!(parsed.monkeys[0], expected[0]);
+        assert_eq!(parsed.monkeys.len(), 4);
+    }
+
+    #[test]
+    fn it_can_resume_from_checkpoint() {
+        let path = std::env::temp_dir().join(format!("advent-day-11-{}.json", std::process::id()));
+        let checkpoint = Checkpoint::new(path, 5);
+        // a run interrupted after 10 rounds
+        let mut interrupted = parse_input(INPUT_MONKEYS.to_string());
+        for _ in 0..10 {
+            interrupted.play_round();
+        }
+        let input = arena_fingerprint(&parse_input(INPUT_MONKEYS.to_string()), 20, false);
+        checkpoint.save(input, &interrupted).unwrap();
+        assert_eq!(checkpoint.load::<MonkeyArena>(input), Some(interrupted.clone()));
+
+        let parsed = parse_input(INPUT_MONKEYS.to_string());
+        let resumed = compute_monkey_business_checkpointed(parsed, 20, false, &checkpoint);
+        let parsed = parse_input(INPUT_MONKEYS.to_string());
+        assert_eq!(resumed, compute_monkey_business(parsed, 20, false));
+        assert_eq!(checkpoint.load::<MonkeyArena>(input), None);
+
+        // saved for 30 rounds, not resumed for 20
+        let other = arena_fingerprint(&parse_input(INPUT_MONKEYS.to_string()), 30, false);
+        checkpoint.save(other, &interrupted).unwrap();
+        let parsed = parse_input(INPUT_MONKEYS.to_string());
+        let fresh = compute_monkey_business_checkpointed(parsed, 20, false, &checkpoint);
+        assert_eq!(fresh, resumed);
+    }
+
+    #[test]
+    fn it_can_compute_business() {
+        println!("Row 1");
+        let parsed = parse_input(INPUT_MONKEYS.to_string());
+        let business = compute_monkey_business(parsed, 1, true);
+        let expected = vec![2, 4, 3, 6];
+        assert_eq!(business, expected);
+
+        println!("Row 20");
+        let parsed = parse_input(INPUT_MONKEYS.to_string());
+        let business = compute_monkey_business(parsed, 20, true);
+        let expected = vec![99, 97, 8, 103];
+        assert_eq!(business, expected);
+
+        println!("Row 1000");
+        let parsed = parse_input(INPUT_MONKEYS.to_string());
+        let business = compute_monkey_business(parsed, 1000, true);
+        let expected = vec![5204, 4792, 199, 5192];
+        assert_eq!(business, expected);
+    }
+}
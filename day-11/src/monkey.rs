@@ -1,20 +1,27 @@
+use advent_common::checkpoint::{fingerprint, Checkpoint};
+use crate::{trace, TraceEvent};
 use regex::Regex;
-use std::fmt::Debug;
-use std::fmt::Formatter;
-use core::fmt::Error;
+use serde::{Deserialize, Serialize};
 
 type MonkeyNumber = usize;
 type WorryItem = f64;
 
-#[derive(Debug)]
-pub(crate) struct MonkeyArena {
+/// Monkeys with the items they hold, played round by round
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MonkeyArena {
     round: usize,
     no_worries: bool,
-    pub(crate) monkeys: Vec<Monkey>,
+    pub monkeys: Vec<Monkey>,
+}
+
+impl Default for MonkeyArena {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl MonkeyArena {
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         MonkeyArena {
             round: 0,
             no_worries: false,
@@ -22,7 +29,7 @@ impl MonkeyArena {
         }
     }
 
-    pub(crate) fn get_monkey_business(&self) -> Vec<usize> {
+    pub fn get_monkey_business(&self) -> Vec<usize> {
         let mut results = vec![];
         for i_monkey in 0..self.monkeys.len() {
             results.push(self.monkeys[i_monkey].inspect_count)
@@ -30,80 +37,86 @@ impl MonkeyArena {
         results
     }
 
-    pub(crate) fn play_round(&mut self) {
+    pub fn play_round(&mut self) {
+        // without the relief the worry grows past what a f64 holds exactly,
+        // only its remainder by every divisor decides where an item goes
+        let modulus: WorryItem = self.monkeys.iter().map(|m| m.divisor).product();
         for i_monkey in 0..self.monkeys.len() {
             let processed = self.monkeys[i_monkey].process_items();
             for res in processed {
-                let (dest, item) = res;
+                let (dest, mut item) = res;
+                if self.no_worries {
+                    item %= modulus;
+                }
+                trace::emit(TraceEvent::ItemThrown { round: self.round + 1, from: i_monkey, to: dest, worry: item });
                 self.monkeys[dest].items.push(item);
             }
         }
         self.round += 1;
     }
 
-    pub(crate) fn set_no_worries(&mut self, value: bool) {
+    /// Rounds played so far
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    pub fn set_no_worries(&mut self, value: bool) {
+        self.no_worries = value;
         for monkey in self.monkeys.iter_mut() {
             monkey.no_worries = value;
         }
     }
 }
 
-pub(crate) struct Monkey {
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Monkey {
     number: MonkeyNumber,
     items: Vec<WorryItem>,
     inspect_count: usize,
     no_worries: bool,
-    operation: Box<dyn Fn(WorryItem) -> WorryItem>,
-    condition: Box<dyn Fn(WorryItem) -> bool>,
+    operation: Operation,
+    // items divisible by it go to `send_true`
+    divisor: WorryItem,
     send_true: MonkeyNumber,
     send_false: MonkeyNumber,
 }
 
-impl Debug for Monkey {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.debug_struct("Monkey")
-            .field("number", &self.number)
-            .field("items", &self.items)
-            .field("inspect_count", &self.inspect_count)
-            .field("no_worries", &self.no_worries)
-            .field("send_true", &self.send_true)
-            .field("send_false", &self.send_false)
-            .finish()
-    }
+// the operations were closures, an enum can be saved in a checkpoint
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum Operation {
+    Add(WorryItem),
+    Multiply(WorryItem),
+    Double,
+    Square,
 }
 
-impl PartialEq for Monkey {
-    fn eq(&self, other: &Self) -> bool {
-        self.number == other.number &&
-        self.items == other.items &&
-        self.inspect_count == other.inspect_count &&
-        self.no_worries == other.no_worries &&
-        self.send_true == other.send_true &&
-        self.send_false == other.send_false
+impl Operation {
+    fn apply(&self, old: WorryItem) -> WorryItem {
+        match self {
+            Operation::Add(n) => old + n,
+            Operation::Multiply(n) => old * n,
+            Operation::Double => old + old,
+            Operation::Square => old * old,
+        }
     }
 }
 
-
-fn op_creator(lh: String, operator: String, rh: String) -> Box<dyn Fn(WorryItem) -> WorryItem> {
+fn op_creator(operator: String, rh: String) -> Operation {
     if rh == "old" {
         match operator.as_str() {
-            "+" => return Box::new(move |o| o + o),
-            "*" => return Box::new(move |o| o * o),
+            "+" => return Operation::Double,
+            "*" => return Operation::Square,
             _ => panic!("no old operator"),
         }
     }
     let rh_num = rh.parse::<WorryItem>().unwrap();
     match operator.as_str() {
-        "+" => return Box::new(move |o| o + rh_num.clone()),
-        "*" => return Box::new(move |o| o * rh_num.clone()),
+        "+" => Operation::Add(rh_num),
+        "*" => Operation::Multiply(rh_num),
         _ => panic!("no old operator"),
     }
 }
 
-fn cond_creator(num: f64) -> Box<dyn Fn(WorryItem) -> bool> {
-    Box::new(move |x| { (x % num) == 0.0 })
-}
-
 impl From<String> for Monkey {
     fn from(item: String) -> Self {
         let lines: Vec<String> = item.lines().collect::<Vec<&str>>().iter()
@@ -122,17 +135,15 @@ impl From<String> for Monkey {
         let (_, right) = lines[2].split_once("new = ").unwrap();
         let re_operation = Regex::new(r"(\w+) ([\+\*]) (\w+)").unwrap();
         let caps_operation = re_operation.captures(right).unwrap();
-        let op_lh = caps_operation.get(1).unwrap()
-            .as_str().to_string();
         let op_or = caps_operation.get(2).unwrap()
             .as_str().to_string();
         let op_rh = caps_operation.get(3).unwrap()
             .as_str().to_string();
-        // println!("{:?} - {:?} - {:?}", op_lh, op_or, op_rh);
-        let operation = op_creator(op_lh, op_or, op_rh);
+        // left hand side is always old
+        let operation = op_creator(op_or, op_rh);
         // test func
         let (_, right) = lines[3].split_once("divisible by ").unwrap();
-        let condition = cond_creator(right.parse::<f64>().unwrap());
+        let divisor = right.parse::<WorryItem>().unwrap();
         // send_true
         let re_throw_to = Regex::new(r"throw to monkey (\d+)").unwrap();
         let caps_send_true = re_throw_to.captures(&lines[4]).unwrap();
@@ -149,7 +160,7 @@ impl From<String> for Monkey {
             no_worries: false,
             items: starting_nums,
             operation,
-            condition,
+            divisor,
             send_true,
             send_false,
         }
@@ -157,18 +168,20 @@ impl From<String> for Monkey {
 }
 
 impl Monkey {
+    /// Worry levels of the items held, in throwing order
+    pub fn items(&self) -> &[WorryItem] {
+        &self.items
+    }
+
     fn process_one_item(&self, item: WorryItem) -> (MonkeyNumber, WorryItem) {
-        let after_op = (self.operation)(item);
+        let after_op = self.operation.apply(item);
         let mut after_divide = after_op;
         if !self.no_worries {
             after_divide = (after_op / 3.0).floor();
         } else {
             // ahah
         }
-        if after_divide == 0.0 {
-            println!("it's 0");
-        }
-        match (self.condition)(after_divide) {
+        match after_divide % self.divisor == 0.0 {
             true => (self.send_true, after_divide),
             false => (self.send_false, after_divide),
         }
@@ -187,13 +200,40 @@ impl Monkey {
     } 
 }
 
-pub(crate) fn compute_monkey_business(mut arena: MonkeyArena, rounds: usize, no_worries: bool) -> Vec<usize> {
+pub fn compute_monkey_business(mut arena: MonkeyArena, rounds: usize, no_worries: bool) -> Vec<usize> {
+    arena.set_no_worries(no_worries);
+    for _ in 0..rounds {
+        arena.play_round();
+    }
+    arena.get_monkey_business()
+}
+
+// identifies the game a checkpoint was saved for
+pub(crate) fn arena_fingerprint(arena: &MonkeyArena, rounds: usize, no_worries: bool) -> u64 {
+    fingerprint(&(arena, rounds, no_worries))
+}
+
+/// Same as `compute_monkey_business`, saving the arena to `checkpoint`
+/// and starting again from the arena saved for the same game when there is one
+pub fn compute_monkey_business_checkpointed(
+    arena: MonkeyArena,
+    rounds: usize,
+    no_worries: bool,
+    checkpoint: &Checkpoint,
+) -> Vec<usize> {
+    let input = arena_fingerprint(&arena, rounds, no_worries);
+    let mut arena = checkpoint.load(input).unwrap_or(arena);
     arena.set_no_worries(no_worries);
-    for r in 0..rounds {
+    while arena.round < rounds {
         arena.play_round();
+        if checkpoint.is_due(arena.round) {
+            if let Err(e) = checkpoint.save(input, &arena) {
+                eprintln!("Could not save the checkpoint: {}", e);
+            }
+        }
     }
-    let businesses = arena.get_monkey_business();
-    businesses
+    checkpoint.clear();
+    arena.get_monkey_business()
 }
 
 #[cfg(test)]
@@ -215,8 +255,8 @@ mod monkey_test {
             items: vec![79.0, 98.0],
             inspect_count: 0,
             no_worries: false,
-            operation: Box::new(|old| old * 19.0),
-            condition: Box::new(|x| x % 23.0 == 0.0),
+            operation: Operation::Multiply(19.0),
+            divisor: 23.0,
             send_true: 2,
             send_false: 3,
         };
@@ -234,7 +274,7 @@ mod monkey_test {
             (3, 620.0),
         ];
         
-        assert_eq!(monkey.items, vec![]);
+        assert_eq!(monkey.items, Vec::<WorryItem>::new());
         assert_eq!(results, expected);
     }
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+use day_11::{compute_monkey_business, parse_input};
+
+const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[test]
+fn it_can_count_inspections() {
+    let arena = parse_input(EXAMPLE.to_string());
+    assert_eq!(compute_monkey_business(arena, 20, false), vec![101, 95, 7, 105]);
+}
@@ -1,5 +1,6 @@
 """Main module for the project."""
 import os
+import sys
 import ast
 from itertools import zip_longest
 from typing import Union, List, Tuple
@@ -96,8 +97,7 @@ def order_packets(list_of_packets: List) -> List:
     logger.debug(f"Ordering {len(list_of_packets)} packets")
     cmp_func = functools.cmp_to_key(comparison_order)
     list_of_packets.sort(key=cmp_func)
-    from pprint import pprint
-    pprint(list_of_packets)
+    logger.debug(f"Ordered packets: {list_of_packets}")
     return list_of_packets
 
 
@@ -132,7 +132,8 @@ def get_cookie_value() -> str:
     return value
 
 def _build_problem_url(day: int) -> str:
-    return f"https://adventofcode.com/2022/day/{day}/input"
+    base_url = os.environ.get("ADVENT_BASE_URL", "https://adventofcode.com")
+    return f"{base_url}/2022/day/{day}/input"
 
 def get_problem_input() -> str:
     logger.info("Getting problem input")
@@ -144,8 +145,30 @@ def get_problem_input() -> str:
     )
     return response.text
 
+def format_answers(first_solution, second_solution) -> str:
+    """Format the answers for the line protocol used by the Rust runner.
+
+    Each line is `<part> <answer>`, logs stay on stderr.
+    """
+    return f"1 {first_solution}\n2 {second_solution}\n"
+
+
+def run_from_stdin() -> str:
+    """Solve the input piped on stdin and write the answers on stdout."""
+    input_string = sys.stdin.read()
+    answers = format_answers(
+        solve_first_part(input_string),
+        solve_second_part(input_string),
+    )
+    sys.stdout.write(answers)
+    sys.stdout.flush()
+    return "ok"
+
+
 def main():
     """Define the main function for the project."""
+    if "--stdin" in sys.argv[1:]:
+        return run_from_stdin()
     input_string = get_problem_input()
     first_solution = solve_first_part(input_string)
     logger.info(f"First solution is: {first_solution}")
@@ -6,7 +6,10 @@ from day13py.main import (
     solve_second_part,
     prepare_for_second_part,
     order_packets,
+    format_answers,
+    run_from_stdin,
 )
+import io
 import pytest
 
 
@@ -105,3 +108,14 @@ def test_order_packets():
 def test_solve_second_part():
     second_solution = solve_second_part(FIRST_EXAMPLE)
     assert second_solution == 140
+
+
+def test_format_answers():
+    assert format_answers(13, 140) == "1 13\n2 140\n"
+
+
+def test_run_from_stdin(monkeypatch, capsys):
+    monkeypatch.setattr("sys.stdin", io.StringIO(FIRST_EXAMPLE))
+    assert run_from_stdin() == "ok"
+    captured = capsys.readouterr()
+    assert captured.out == "1 13\n2 140\n"
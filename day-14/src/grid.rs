@@ -1,23 +1,33 @@
 use super::point::Point;
+use crate::{trace, TraceEvent};
 use std::cmp::{max, min};
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
 const MAX_DEPTH: usize = 500;
 const FLOOR_OFFSET: usize = 2;
 
-#[derive(PartialEq, Clone, Default)]
-pub(crate) struct FallingGrid {
+/// Rocks and resting sand of the cave, with an optional floor
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct FallingGrid {
     filled: HashSet<Point>,
 }
 
+/// Second part in progress: the grid with the floor and the grains dropped so far
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FloorSimulation {
+    pub grid: FallingGrid,
+    pub grains: usize,
+}
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub(crate) enum GrainStatus {
+pub enum GrainStatus {
     Stopped,
     Falling,
 }
 
 impl FallingGrid {
-    pub(crate) fn add_line(&mut self, start: Point, end: Point) {
+    pub fn add_line(&mut self, start: Point, end: Point) {
         let range_x = min(start.x, end.x)..max(start.x, end.x);
         let range_y = min(start.y, end.y)..max(start.y, end.y);
 
@@ -42,7 +52,7 @@ impl FallingGrid {
         self.filled.insert(p);
     }
 
-    pub(crate) fn set_floor(&mut self) {
+    pub fn set_floor(&mut self) {
         // find lowest point
         let lowest_y = self
             .filled
@@ -59,11 +69,11 @@ impl FallingGrid {
         }
     }
 
-    fn get_number_of_points(&self) -> usize {
+    pub fn get_number_of_points(&self) -> usize {
         self.filled.len()
     }
 
-    pub(crate) fn fall_one_sand(&mut self, start: Point) -> GrainStatus {
+    pub fn fall_one_sand(&mut self, start: Point) -> GrainStatus {
         let mut current = start;
         let mut status = GrainStatus::Falling;
         while current.y < MAX_DEPTH && status == GrainStatus::Falling {
@@ -80,6 +90,7 @@ impl FallingGrid {
                     current.x += 1;
                 }
                 (false, false, false) => {
+                    trace::emit(TraceEvent::GrainSettled { x: current.x, y: current.y });
                     self.add_fixed_point(current);
                     status = GrainStatus::Stopped;
                 }
@@ -89,12 +100,7 @@ impl FallingGrid {
     }
 
     fn are_under_free(&self, p: &Point) -> (bool, bool, bool) {
-        let mut p_x_left = p.x;
-        if p.x == 0 {
-            p_x_left = 0;
-        } else {
-            p_x_left = p.x - 1;
-        }
+        let p_x_left = if p.x == 0 { 0 } else { p.x - 1 };
         let p_diag_left = Point {
             x: p_x_left,
             y: p.y + 1,
@@ -114,11 +120,11 @@ impl FallingGrid {
         )
     }
 
-    pub(crate) fn is_point_free(&self, p: &Point) -> bool {
+    pub fn is_point_free(&self, p: &Point) -> bool {
         !self.filled.contains(p)
     }
 
-    pub(crate) fn is_point_occupied(&self, p: &Point) -> bool {
+    pub fn is_point_occupied(&self, p: &Point) -> bool {
         self.filled.contains(p)
     }
 }
@@ -134,7 +140,7 @@ mod falling_grid_test {
         let p = Point { x: 0, y: 0 };
         fg.add_fixed_point(p);
         assert_eq!(fg.get_number_of_points(), 1);
-        assert_eq!(fg.is_point_free(&p), false);
+        assert!(!fg.is_point_free(&p));
         assert!(fg.is_point_occupied(&p));
     }
 
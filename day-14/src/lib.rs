@@ -0,0 +1,208 @@
+//! Day 14: Regolith Reservoir, sand falling in a cave.
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub use advent_common::Checkpoint;
+use advent_common::checkpoint::fingerprint;
+pub mod point;
+pub use point::Point;
+pub mod grid;
+pub use grid::{FallingGrid, FloorSimulation, GrainStatus};
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = 14;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    GrainSettled { x: usize, y: usize },
+}
+
+pub type AdventParsed = Vec<Vec<Point>>;
+pub type AdventResponse = usize;
+
+/// Where the sand comes from
+pub const STARTING_FALL: Point = Point { x: 500, y: 0 };
+// grains of the second part between two checkpoints
+const CHECKPOINT_EVERY: usize = 1000;
+
+pub fn parse_line(line: String) -> Vec<Point> {
+    line.split(" -> ")
+        .map(|s| Point::from(s.to_string()))
+        .collect()
+}
+
+/// Reads each line as the path of a rock wall
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut result = vec![];
+    for line in puzzle_input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+        result.push(parse_line(line.to_string()));
+    }
+    result
+}
+
+pub fn prepare_grid(parsed: AdventParsed) -> FallingGrid {
+    let mut fg = FallingGrid::default();
+    for line in parsed.iter() {
+        let mut previous = line[0];
+        for point in line.iter().cloned() {
+            if previous == point {
+                continue;
+            }
+            fg.add_line(previous, point);
+            previous = point;
+        }
+    }
+    fg
+}
+
+/// Grains of sand at rest before they fall in the abyss
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    // Create Grid with walls
+    let mut fg = prepare_grid(parsed);
+    // Make grains fall
+    let mut last_status = GrainStatus::Stopped;
+    let mut count_grain: usize = 0;
+    while last_status == GrainStatus::Stopped {
+        last_status = fg.fall_one_sand(STARTING_FALL);
+        if last_status == GrainStatus::Stopped {
+            count_grain += 1;
+        }
+    }
+    count_grain
+}
+
+fn start_floor_simulation(parsed: AdventParsed) -> FloorSimulation {
+    // Create Grid with walls
+    let mut fg = prepare_grid(parsed);
+    // Add floor at bottom
+    fg.set_floor();
+    FloorSimulation {
+        grid: fg,
+        grains: 0,
+    }
+}
+
+// `checkpoint` comes with the fingerprint of the input
+fn finish_floor_simulation(mut simulation: FloorSimulation, checkpoint: Option<(&Checkpoint, u64)>) -> AdventResponse {
+    // Make grains fall
+    while simulation.grid.is_point_free(&STARTING_FALL) {
+        simulation.grid.fall_one_sand(STARTING_FALL);
+        simulation.grains += 1;
+        if let Some((checkpoint, input)) = checkpoint {
+            if checkpoint.is_due(simulation.grains) {
+                if let Err(e) = checkpoint.save(input, &simulation) {
+                    eprintln!("Could not save the checkpoint: {}", e);
+                }
+            }
+        }
+    }
+    if let Some((checkpoint, _)) = checkpoint {
+        checkpoint.clear();
+    }
+    simulation.grains - 1
+}
+
+/// Grains of sand at rest before the source is blocked, with a floor
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    finish_floor_simulation(start_floor_simulation(parsed), None)
+}
+
+/// Same as `solve_two`, saving the simulation to `checkpoint` and resuming from it
+pub fn solve_two_checkpointed(parsed: AdventParsed, checkpoint: &Checkpoint) -> AdventResponse {
+    let input = fingerprint(&parsed);
+    let simulation = checkpoint
+        .load(input)
+        .unwrap_or_else(|| start_floor_simulation(parsed));
+    finish_floor_simulation(simulation, Some((checkpoint, input)))
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    // `--checkpoint PATH` to resume an interrupted run
+    let second_solution = match Checkpoint::from_env(CHECKPOINT_EVERY) {
+        Some(checkpoint) => solve_two_checkpointed(parsed, &checkpoint),
+        None => solve_two(parsed),
+    };
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day_test {
+    use super::*;
+
+    const EXAMPLE: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn it_can_parse_single_line() {
+        let line = String::from("498,4 -> 498,6 -> 496,6");
+        let result = parse_line(line);
+        let expected = vec![
+            Point { x: 498, y: 4 },
+            Point { x: 498, y: 6 },
+            Point { x: 496, y: 6 },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let expected = vec![
+            vec![
+                Point { x: 498, y: 4 },
+                Point { x: 498, y: 6 },
+                Point { x: 496, y: 6 },
+            ],
+            vec![
+                Point { x: 503, y: 4 },
+                Point { x: 502, y: 4 },
+                Point { x: 502, y: 9 },
+                Point { x: 494, y: 9 },
+            ],
+        ];
+        assert_eq!(parsed[0], expected[0]);
+        assert_eq!(parsed, expected);
+    }
+
+
+    #[test]
+    fn it_can_resume_from_checkpoint() {
+        let path = std::env::temp_dir().join(format!("advent-day-14-{}.json", std::process::id()));
+        let checkpoint = Checkpoint::new(path, 10);
+        // a run interrupted after 40 grains
+        let mut interrupted = start_floor_simulation(parse_input(EXAMPLE.to_string()));
+        for _ in 0..40 {
+            interrupted.grid.fall_one_sand(STARTING_FALL);
+            interrupted.grains += 1;
+        }
+        let input = fingerprint(&parse_input(EXAMPLE.to_string()));
+        checkpoint.save(input, &interrupted).unwrap();
+        assert_eq!(checkpoint.load::<FloorSimulation>(input), Some(interrupted.clone()));
+
+        let parsed = parse_input(EXAMPLE.to_string());
+        assert_eq!(solve_two_checkpointed(parsed, &checkpoint), 93);
+        assert_eq!(checkpoint.load::<FloorSimulation>(input), None);
+
+        // a cave without the last wall, its grains are not resumed here
+        let other = fingerprint(&parse_input(EXAMPLE.lines().next().unwrap().to_string()));
+        checkpoint.save(other, &interrupted).unwrap();
+        let parsed = parse_input(EXAMPLE.to_string());
+        assert_eq!(solve_two_checkpointed(parsed, &checkpoint), 93);
+    }
+}
@@ -1,7 +1,9 @@
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub(crate) struct Point {
-    pub(crate) x: usize,
-    pub(crate) y: usize,
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
 }
 
 impl From<String> for Point {
@@ -0,0 +1,11 @@
+use day_14::{parse_input, prepare_grid, GrainStatus, Point};
+
+const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[test]
+fn it_can_drop_sand() {
+    let mut grid = prepare_grid(parse_input(EXAMPLE.to_string()));
+    let start = Point { x: 500, y: 0 };
+    assert_eq!(grid.fall_one_sand(start), GrainStatus::Stopped);
+    assert!(grid.is_point_occupied(&Point { x: 500, y: 8 }));
+}
@@ -0,0 +1,71 @@
+//! Day XX: 
+//!
+//! `parse_input` then `solve_one` and `solve_two` give the answers,
+//! `run` does it all from an [`InputSource`].
+
+pub use advent_common::trace;
+pub use advent_common::InputSource;
+
+use serde::Serialize;
+
+/// Day of the puzzle, used to find its input
+pub const DAY: u8 = ;
+
+/// Events written with `--trace`, one JSON line each, see the `trace` module
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    // e.g. Found { position: usize },
+}
+
+pub type AdventParsed = ;
+pub type AdventResponse = ;
+
+/// Reads the raw puzzle input
+pub fn parse_input(puzzle_input: String) -> AdventParsed {
+    let mut result = vec![];
+    todo!();
+}
+
+pub fn solve_one(parsed: AdventParsed) -> AdventResponse {
+    let mut result = 0;
+    for x in parsed.iter() {
+        todo!();
+    }
+    result
+}
+
+pub fn solve_two(parsed: AdventParsed) -> AdventResponse {
+    let mut result = 0;
+    for x in parsed.iter() {
+        todo!();
+    }
+    result
+}
+
+/// Reads the input from `source`, then prints the solutions of both parts
+pub fn run(source: InputSource) {
+    let _trace = trace::start_from_env(DAY);
+    let raw_input = source.read_input_or_exit(DAY, advent_common::embedded_input!());
+    let parsed = parse_input(raw_input);
+    let first_solution = solve_one(parsed.clone());
+    println!("First solution: {:?}", first_solution);
+    let second_solution = solve_two(parsed);
+    println!("Second solution: {:?}", second_solution);
+}
+
+#[cfg(test)]
+mod day_test {
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn it_can_parse_example() {
+        let parsed = parse_input(EXAMPLE.to_string());
+        let expected = vec![
+        ];
+        assert_eq!(parsed[0], expected[0]);
+        assert_eq!(parsed, expected);
+    }
+}